@@ -1,14 +1,42 @@
 use crate::errors::ContractError;
 use crate::events::{
-    publish_account_initialized_event, publish_account_verified_event, publish_token_added_event,
+    publish_account_initialized_event, publish_account_verified_event,
+    publish_approval_threshold_set_event, publish_passkey_added_event,
+    publish_passkey_removed_event, publish_payout_allowlist_set_event, publish_refund_event,
+    publish_signer_key_added_event, publish_signer_key_removed_event, publish_signers_set_event,
+    publish_timelock_cancelled_event, publish_timelock_executed_event, publish_token_added_event,
+    publish_verification_set_event, publish_withdrawal_approved_event,
+    publish_withdrawal_cancelled_event, publish_withdrawal_delay_set_event,
+    publish_withdrawal_event, publish_withdrawal_executed_event,
+    publish_withdrawal_limit_set_event, publish_withdrawal_proposed_event,
+    publish_withdrawal_queued_event, publish_withdrawal_vetoed_event,
 };
 use crate::interface::MerchantAccountTrait;
-use crate::types::{AccountInfo, DataKey, TokenBalance};
-use soroban_sdk::{contract, contractimpl, panic_with_error, token, Address, Env, Vec};
+use crate::types::{
+    AccountInfo, AccountSignature, DataKey, PasskeySigner, ProposalStatus, QueuedWithdrawal,
+    SignerPermissions, TokenBalance, WithdrawalProposal, WithdrawalRecord, WithdrawalStatus,
+};
+use crate::webauthn;
+use soroban_sdk::auth::{Context, ContractContext, CustomAccountInterface};
+use soroban_sdk::crypto::Hash;
+use soroban_sdk::{
+    contract, contractimpl, panic_with_error, symbol_short, token, Address, BytesN, Env, Symbol,
+    TryIntoVal, Vec,
+};
+
+pub const WITHDRAWAL_WINDOW: u64 = 24 * 60 * 60;
+pub const PROPOSAL_LIFETIME: u64 = 7 * 24 * 60 * 60;
 
 #[contract]
 pub struct MerchantAccount;
 
+fn get_merchant_address(env: &Env) -> Address {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Merchant)
+        .unwrap_or_else(|| panic_with_error!(env, ContractError::NotInitialized))
+}
+
 fn get_manager(env: &Env) -> Address {
     env.storage()
         .persistent()
@@ -32,6 +60,395 @@ fn token_exists(tracked_tokens: &Vec<Address>, token: &Address) -> bool {
     false
 }
 
+fn get_recent_withdrawals(env: &Env, key: &DataKey) -> Vec<WithdrawalRecord> {
+    let withdrawals: Vec<WithdrawalRecord> = env
+        .storage()
+        .persistent()
+        .get(key)
+        .unwrap_or_else(|| Vec::new(env));
+
+    let now = env.ledger().timestamp();
+    let mut recent = Vec::new(env);
+    for withdrawal in withdrawals.iter() {
+        if withdrawal.timestamp + WITHDRAWAL_WINDOW > now {
+            recent.push_back(withdrawal);
+        }
+    }
+    recent
+}
+
+// Policy shared by every path that moves funds out of the account:
+// verification, the payout allowlist and the rolling withdrawal limit.
+// On success the amount is recorded against the limit window. Burns have
+// no recipient, so they are refused while an allowlist is set.
+fn apply_withdrawal_policy(
+    env: &Env,
+    token: &Address,
+    amount: i128,
+    to: Option<&Address>,
+) -> Result<(), ContractError> {
+    if amount <= 0 {
+        return Err(ContractError::InvalidAmount);
+    }
+    let verified: bool = env
+        .storage()
+        .persistent()
+        .get(&DataKey::Verified)
+        .unwrap_or(false);
+    if !verified {
+        return Err(ContractError::AccountNotVerified);
+    }
+
+    let allowlist: Vec<Address> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::PayoutAllowlist)
+        .unwrap_or_else(|| Vec::new(env));
+    if !allowlist.is_empty() && !to.is_some_and(|to| allowlist.contains(to)) {
+        return Err(ContractError::PayoutAddressNotAllowed);
+    }
+
+    let mut withdrawals = get_recent_withdrawals(env, &DataKey::Withdrawals(token.clone()));
+    let limit: Option<i128> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::WithdrawalLimit(token.clone()));
+    if let Some(limit) = limit {
+        if sum_withdrawals(&withdrawals) + amount > limit {
+            return Err(ContractError::WithdrawalLimitExceeded);
+        }
+    }
+
+    withdrawals.push_back(WithdrawalRecord {
+        amount,
+        timestamp: env.ledger().timestamp(),
+    });
+    env.storage()
+        .persistent()
+        .set(&DataKey::Withdrawals(token.clone()), &withdrawals);
+    Ok(())
+}
+
+fn transfer_out(env: &Env, token: &Address, amount: i128, to: &Address) {
+    if let Err(error) = apply_withdrawal_policy(env, token, amount, Some(to)) {
+        panic_with_error!(env, error);
+    }
+
+    let token_client = token::TokenClient::new(env, token);
+    let contract_address = env.current_contract_address();
+    if token_client.balance(&contract_address) < amount {
+        panic_with_error!(env, ContractError::InsufficientBalance);
+    }
+
+    token_client.transfer(&contract_address, to, &amount);
+    publish_withdrawal_event(
+        env,
+        token.clone(),
+        amount,
+        to.clone(),
+        env.ledger().timestamp(),
+    );
+}
+
+// Everything the merchant moves out without signer approval counts
+// toward the approval threshold over the rolling window, so one large
+// withdrawal cannot be split into several small ones.
+fn apply_approval_threshold(env: &Env, token: &Address, amount: i128) -> Result<(), ContractError> {
+    let approval_amount: Option<i128> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::ApprovalThreshold(token.clone()));
+    if let Some(approval_amount) = approval_amount {
+        let key = DataKey::UnapprovedWithdrawals(token.clone());
+        let mut withdrawals = get_recent_withdrawals(env, &key);
+        if sum_withdrawals(&withdrawals) + amount > approval_amount {
+            return Err(ContractError::ApprovalRequired);
+        }
+        withdrawals.push_back(WithdrawalRecord {
+            amount,
+            timestamp: env.ledger().timestamp(),
+        });
+        env.storage().persistent().set(&key, &withdrawals);
+    }
+    Ok(())
+}
+
+fn assert_within_approval_threshold(env: &Env, token: &Address, amount: i128) {
+    if let Err(error) = apply_approval_threshold(env, token, amount) {
+        panic_with_error!(env, error);
+    }
+}
+
+fn get_pending_withdrawal_ids(env: &Env) -> Vec<u64> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PendingWithdrawals)
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+fn get_queued_withdrawal_record(env: &Env, withdrawal_id: u64) -> QueuedWithdrawal {
+    env.storage()
+        .persistent()
+        .get(&DataKey::QueuedWithdrawal(withdrawal_id))
+        .unwrap_or_else(|| panic_with_error!(env, ContractError::WithdrawalNotFound))
+}
+
+fn close_queued_withdrawal(env: &Env, withdrawal: &mut QueuedWithdrawal, status: WithdrawalStatus) {
+    if withdrawal.status != WithdrawalStatus::Pending {
+        panic_with_error!(env, ContractError::WithdrawalNotPending);
+    }
+    withdrawal.status = status;
+    env.storage()
+        .persistent()
+        .set(&DataKey::QueuedWithdrawal(withdrawal.id), withdrawal);
+
+    let mut pending = get_pending_withdrawal_ids(env);
+    if let Some(index) = pending.first_index_of(withdrawal.id) {
+        pending.remove(index);
+    }
+    env.storage()
+        .persistent()
+        .set(&DataKey::PendingWithdrawals, &pending);
+}
+
+fn get_signer_key_permissions(env: &Env, public_key: &BytesN<32>) -> Option<SignerPermissions> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::SignerKey(public_key.clone()))
+}
+
+fn get_passkey_signer(env: &Env, public_key: &BytesN<65>) -> Option<PasskeySigner> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Passkey(public_key.clone()))
+}
+
+fn passkey_id(env: &Env, public_key: &BytesN<65>) -> BytesN<32> {
+    env.crypto().sha256(&public_key.clone().into()).to_bytes()
+}
+
+fn authorize_context(
+    env: &Env,
+    key_id: &BytesN<32>,
+    permissions: &SignerPermissions,
+    context: &Context,
+) -> Result<(), ContractError> {
+    let call = match context {
+        Context::Contract(call) => call,
+        _ => return Err(ContractError::CallNotPermitted),
+    };
+
+    if let Some((to_index, amount_index)) = spend_arg_indices(env, &call.fn_name) {
+        return authorize_spend(env, key_id, permissions, call, to_index, amount_index);
+    }
+
+    // A wildcard never covers the account's own admin functions or token
+    // allowances; those need the function named explicitly.
+    let explicit_only =
+        call.contract == env.current_contract_address() || call.fn_name == symbol_short!("approve");
+    for permission in permissions.allowed_calls.iter() {
+        if permission.contract != call.contract {
+            continue;
+        }
+        match permission.function {
+            Some(function) if function == call.fn_name => return Ok(()),
+            None if !explicit_only => return Ok(()),
+            _ => {}
+        }
+    }
+    Err(ContractError::CallNotPermitted)
+}
+
+// Positions of the recipient and amount arguments for token calls that
+// move funds. Burns have no recipient.
+fn spend_arg_indices(env: &Env, fn_name: &Symbol) -> Option<(Option<u32>, u32)> {
+    if *fn_name == symbol_short!("transfer") {
+        Some((Some(1), 2))
+    } else if *fn_name == Symbol::new(env, "transfer_from") {
+        Some((Some(2), 3))
+    } else if *fn_name == symbol_short!("burn") {
+        Some((None, 1))
+    } else if *fn_name == symbol_short!("burn_from") {
+        Some((None, 2))
+    } else {
+        None
+    }
+}
+
+fn authorize_spend(
+    env: &Env,
+    key_id: &BytesN<32>,
+    permissions: &SignerPermissions,
+    call: &ContractContext,
+    to_index: Option<u32>,
+    amount_index: u32,
+) -> Result<(), ContractError> {
+    let mut limit = None;
+    for spend_limit in permissions.spend_limits.iter() {
+        if spend_limit.token == call.contract {
+            limit = Some(spend_limit.limit);
+        }
+    }
+    let limit = limit.ok_or(ContractError::CallNotPermitted)?;
+
+    let amount: i128 = call
+        .args
+        .get(amount_index)
+        .and_then(|amount| amount.try_into_val(env).ok())
+        .ok_or(ContractError::InvalidAmount)?;
+    let to: Option<Address> = to_index
+        .and_then(|to_index| call.args.get(to_index))
+        .and_then(|to| to.try_into_val(env).ok());
+
+    let key = DataKey::SignerSpend(key_id.clone(), call.contract.clone());
+    let mut spent = get_recent_withdrawals(env, &key);
+    if amount <= 0 || sum_withdrawals(&spent) + amount > limit {
+        return Err(ContractError::SpendLimitExceeded);
+    }
+
+    // Signer keys spend under the same manager policy as withdraw, and
+    // cannot skip the timelock or the multisig threshold.
+    if get_withdrawal_delay(env) > 0 {
+        return Err(ContractError::TimelockRequired);
+    }
+    apply_approval_threshold(env, &call.contract, amount)?;
+    apply_withdrawal_policy(env, &call.contract, amount, to.as_ref())?;
+
+    spent.push_back(WithdrawalRecord {
+        amount,
+        timestamp: env.ledger().timestamp(),
+    });
+    env.storage().persistent().set(&key, &spent);
+    Ok(())
+}
+
+fn get_signer_set(env: &Env) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Signers)
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+fn assert_signer(env: &Env, signer: &Address) {
+    if !get_signer_set(env).contains(signer) {
+        panic_with_error!(env, ContractError::NotSigner);
+    }
+}
+
+// Once a signer set exists, changes to the multisig policy need the
+// current quorum, not just the merchant key.
+fn assert_signer_quorum(env: &Env, approvers: &Vec<Address>) {
+    let signers = get_signer_set(env);
+    if signers.is_empty() {
+        return;
+    }
+
+    for (i, approver) in approvers.iter().enumerate() {
+        if !signers.contains(&approver) {
+            panic_with_error!(env, ContractError::NotSigner);
+        }
+        if approvers.last_index_of(&approver) != Some(i as u32) {
+            panic_with_error!(env, ContractError::AlreadyApproved);
+        }
+        approver.require_auth();
+    }
+
+    let threshold: u32 = env
+        .storage()
+        .persistent()
+        .get(&DataKey::SignerThreshold)
+        .unwrap_or(0);
+    if approvers.len() < threshold {
+        panic_with_error!(env, ContractError::ThresholdNotMet);
+    }
+}
+
+fn get_withdrawal_delay(env: &Env) -> u64 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::WithdrawalDelay)
+        .unwrap_or(0)
+}
+
+fn enqueue_withdrawal(env: &Env, token: &Address, amount: i128, to: &Address) -> u64 {
+    let withdrawal_id: u64 = env
+        .storage()
+        .persistent()
+        .get(&DataKey::QueuedWithdrawalCount)
+        .unwrap_or(0)
+        + 1;
+    let timestamp = env.ledger().timestamp();
+    let withdrawal = QueuedWithdrawal {
+        id: withdrawal_id,
+        token: token.clone(),
+        amount,
+        to: to.clone(),
+        status: WithdrawalStatus::Pending,
+        queued_at: timestamp,
+        unlocks_at: timestamp + get_withdrawal_delay(env),
+    };
+    env.storage()
+        .persistent()
+        .set(&DataKey::QueuedWithdrawal(withdrawal_id), &withdrawal);
+    env.storage()
+        .persistent()
+        .set(&DataKey::QueuedWithdrawalCount, &withdrawal_id);
+
+    let mut pending = get_pending_withdrawal_ids(env);
+    pending.push_back(withdrawal_id);
+    env.storage()
+        .persistent()
+        .set(&DataKey::PendingWithdrawals, &pending);
+
+    publish_withdrawal_queued_event(
+        env,
+        withdrawal_id,
+        token.clone(),
+        amount,
+        to.clone(),
+        withdrawal.unlocks_at,
+        withdrawal.queued_at,
+    );
+    withdrawal_id
+}
+
+fn get_proposal_record(env: &Env, proposal_id: u64) -> WithdrawalProposal {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Proposal(proposal_id))
+        .unwrap_or_else(|| panic_with_error!(env, ContractError::ProposalNotFound))
+}
+
+fn get_pending_proposal(env: &Env, proposal_id: u64) -> WithdrawalProposal {
+    let proposal = get_proposal_record(env, proposal_id);
+    if proposal.status != ProposalStatus::Pending {
+        panic_with_error!(env, ContractError::ProposalNotPending);
+    }
+    if env.ledger().timestamp() >= proposal.expires_at {
+        panic_with_error!(env, ContractError::ProposalExpired);
+    }
+    proposal
+}
+
+fn count_approvals(env: &Env, proposal: &WithdrawalProposal) -> u32 {
+    let signers = get_signer_set(env);
+    let mut count = 0;
+    for approver in proposal.approvals.iter() {
+        if signers.contains(&approver) {
+            count += 1;
+        }
+    }
+    count
+}
+
+fn sum_withdrawals(withdrawals: &Vec<WithdrawalRecord>) -> i128 {
+    let mut total = 0;
+    for withdrawal in withdrawals.iter() {
+        total += withdrawal.amount;
+    }
+    total
+}
+
 #[contractimpl]
 impl MerchantAccountTrait for MerchantAccount {
     fn initialize(env: Env, merchant: Address, manager: Address, merchant_id: u64) {
@@ -59,10 +476,7 @@ impl MerchantAccountTrait for MerchantAccount {
         );
     }
     fn get_merchant(env: Env) -> Address {
-        env.storage()
-            .persistent()
-            .get(&DataKey::Merchant)
-            .unwrap_or_else(|| panic_with_error!(&env, ContractError::NotInitialized))
+        get_merchant_address(&env)
     }
 
     fn add_token(env: Env, token: Address) {
@@ -115,10 +529,484 @@ impl MerchantAccountTrait for MerchantAccount {
         publish_account_verified_event(&env, env.ledger().timestamp());
     }
 
+    fn set_verified(env: Env, verified: bool) {
+        let manager = get_manager(&env);
+        manager.require_auth();
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::Verified, &verified);
+        publish_verification_set_event(&env, verified, env.ledger().timestamp());
+    }
+
     fn is_verified_account(env: Env) -> bool {
         env.storage()
             .persistent()
             .get(&DataKey::Verified)
             .unwrap_or(false)
     }
+
+    fn set_payout_allowlist(env: Env, addresses: Vec<Address>) {
+        let manager = get_manager(&env);
+        manager.require_auth();
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::PayoutAllowlist, &addresses);
+        publish_payout_allowlist_set_event(&env, addresses, env.ledger().timestamp());
+    }
+
+    fn get_payout_allowlist(env: Env) -> Vec<Address> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::PayoutAllowlist)
+            .unwrap_or_else(|| Vec::new(&env))
+    }
+
+    fn set_withdrawal_limit(env: Env, token: Address, limit: Option<i128>) {
+        let manager = get_manager(&env);
+        manager.require_auth();
+
+        let key = DataKey::WithdrawalLimit(token.clone());
+        match limit {
+            Some(limit) => {
+                if limit <= 0 {
+                    panic_with_error!(&env, ContractError::InvalidAmount);
+                }
+                env.storage().persistent().set(&key, &limit);
+            }
+            None => env.storage().persistent().remove(&key),
+        }
+        publish_withdrawal_limit_set_event(&env, token, limit, env.ledger().timestamp());
+    }
+
+    fn get_withdrawal_limit(env: Env, token: Address) -> Option<i128> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::WithdrawalLimit(token))
+    }
+
+    fn get_withdrawn_in_window(env: Env, token: Address) -> i128 {
+        sum_withdrawals(&get_recent_withdrawals(&env, &DataKey::Withdrawals(token)))
+    }
+
+    fn withdraw(env: Env, token: Address, amount: i128, to: Address) {
+        let merchant = get_merchant_address(&env);
+        merchant.require_auth();
+
+        if Self::get_withdrawal_delay(env.clone()) > 0 {
+            panic_with_error!(&env, ContractError::TimelockRequired);
+        }
+        assert_within_approval_threshold(&env, &token, amount);
+        transfer_out(&env, &token, amount, &to);
+    }
+
+    fn refund(env: Env, token: Address, to: Address, amount: i128) {
+        let manager = get_manager(&env);
+        manager.require_auth();
+
+        if amount <= 0 {
+            panic_with_error!(&env, ContractError::InvalidAmount);
+        }
+        let token_client = token::TokenClient::new(&env, &token);
+        let contract_address = env.current_contract_address();
+        if token_client.balance(&contract_address) < amount {
+            panic_with_error!(&env, ContractError::InsufficientBalance);
+        }
+
+        token_client.transfer(&contract_address, &to, &amount);
+        publish_refund_event(&env, token, amount, to, env.ledger().timestamp());
+    }
+
+    fn set_signers(env: Env, approvers: Vec<Address>, signers: Vec<Address>, threshold: u32) {
+        let merchant = get_merchant_address(&env);
+        merchant.require_auth();
+        assert_signer_quorum(&env, &approvers);
+
+        if threshold == 0 || threshold > signers.len() {
+            panic_with_error!(&env, ContractError::InvalidThreshold);
+        }
+        for (i, signer) in signers.iter().enumerate() {
+            for other in signers.iter().skip(i + 1) {
+                if other == signer {
+                    panic_with_error!(&env, ContractError::InvalidThreshold);
+                }
+            }
+        }
+
+        env.storage().persistent().set(&DataKey::Signers, &signers);
+        env.storage()
+            .persistent()
+            .set(&DataKey::SignerThreshold, &threshold);
+        publish_signers_set_event(&env, signers, threshold, env.ledger().timestamp());
+    }
+
+    fn get_signers(env: Env) -> Vec<Address> {
+        get_signer_set(&env)
+    }
+
+    fn get_signer_threshold(env: Env) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::SignerThreshold)
+            .unwrap_or(0)
+    }
+
+    fn set_approval_threshold(
+        env: Env,
+        approvers: Vec<Address>,
+        token: Address,
+        amount: Option<i128>,
+    ) {
+        let merchant = get_merchant_address(&env);
+        merchant.require_auth();
+        assert_signer_quorum(&env, &approvers);
+
+        let key = DataKey::ApprovalThreshold(token.clone());
+        match amount {
+            Some(amount) => {
+                if amount <= 0 {
+                    panic_with_error!(&env, ContractError::InvalidAmount);
+                }
+                env.storage().persistent().set(&key, &amount);
+            }
+            None => env.storage().persistent().remove(&key),
+        }
+        publish_approval_threshold_set_event(&env, token, amount, env.ledger().timestamp());
+    }
+
+    fn get_approval_threshold(env: Env, token: Address) -> Option<i128> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::ApprovalThreshold(token))
+    }
+
+    fn propose_withdrawal(
+        env: Env,
+        proposer: Address,
+        token: Address,
+        amount: i128,
+        to: Address,
+    ) -> u64 {
+        proposer.require_auth();
+        assert_signer(&env, &proposer);
+
+        if amount <= 0 {
+            panic_with_error!(&env, ContractError::InvalidAmount);
+        }
+
+        let proposal_id: u64 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::ProposalCount)
+            .unwrap_or(0)
+            + 1;
+        let timestamp = env.ledger().timestamp();
+        let mut approvals = Vec::new(&env);
+        approvals.push_back(proposer.clone());
+        let proposal = WithdrawalProposal {
+            id: proposal_id,
+            proposer: proposer.clone(),
+            token: token.clone(),
+            amount,
+            to: to.clone(),
+            approvals,
+            status: ProposalStatus::Pending,
+            created_at: timestamp,
+            expires_at: timestamp + PROPOSAL_LIFETIME,
+            queued_withdrawal_id: None,
+        };
+        env.storage()
+            .persistent()
+            .set(&DataKey::Proposal(proposal_id), &proposal);
+        env.storage()
+            .persistent()
+            .set(&DataKey::ProposalCount, &proposal_id);
+
+        publish_withdrawal_proposed_event(
+            &env,
+            proposal_id,
+            proposer,
+            token,
+            amount,
+            to,
+            proposal.expires_at,
+            timestamp,
+        );
+        proposal_id
+    }
+
+    fn approve_withdrawal(env: Env, signer: Address, proposal_id: u64) {
+        signer.require_auth();
+        assert_signer(&env, &signer);
+
+        let mut proposal = get_pending_proposal(&env, proposal_id);
+        if proposal.approvals.contains(&signer) {
+            panic_with_error!(&env, ContractError::AlreadyApproved);
+        }
+        proposal.approvals.push_back(signer.clone());
+        env.storage()
+            .persistent()
+            .set(&DataKey::Proposal(proposal_id), &proposal);
+
+        publish_withdrawal_approved_event(
+            &env,
+            proposal_id,
+            signer,
+            count_approvals(&env, &proposal),
+            env.ledger().timestamp(),
+        );
+    }
+
+    fn execute_withdrawal(env: Env, signer: Address, proposal_id: u64) {
+        signer.require_auth();
+        assert_signer(&env, &signer);
+
+        let mut proposal = get_pending_proposal(&env, proposal_id);
+        if count_approvals(&env, &proposal) < Self::get_signer_threshold(env.clone()) {
+            panic_with_error!(&env, ContractError::ThresholdNotMet);
+        }
+
+        proposal.status = ProposalStatus::Executed;
+        if get_withdrawal_delay(&env) > 0 {
+            proposal.queued_withdrawal_id = Some(enqueue_withdrawal(
+                &env,
+                &proposal.token,
+                proposal.amount,
+                &proposal.to,
+            ));
+        }
+        env.storage()
+            .persistent()
+            .set(&DataKey::Proposal(proposal_id), &proposal);
+
+        if proposal.queued_withdrawal_id.is_none() {
+            transfer_out(&env, &proposal.token, proposal.amount, &proposal.to);
+        }
+        publish_withdrawal_executed_event(&env, proposal_id, signer, env.ledger().timestamp());
+    }
+
+    fn cancel_withdrawal(env: Env, caller: Address, proposal_id: u64) {
+        caller.require_auth();
+        if caller != get_merchant_address(&env) {
+            assert_signer(&env, &caller);
+        }
+
+        let mut proposal = get_proposal_record(&env, proposal_id);
+        if proposal.status != ProposalStatus::Pending {
+            panic_with_error!(&env, ContractError::ProposalNotPending);
+        }
+        proposal.status = ProposalStatus::Cancelled;
+        env.storage()
+            .persistent()
+            .set(&DataKey::Proposal(proposal_id), &proposal);
+
+        publish_withdrawal_cancelled_event(&env, proposal_id, caller, env.ledger().timestamp());
+    }
+
+    fn get_proposal(env: Env, proposal_id: u64) -> WithdrawalProposal {
+        get_proposal_record(&env, proposal_id)
+    }
+
+    fn set_withdrawal_delay(env: Env, delay: u64) {
+        let manager = get_manager(&env);
+        manager.require_auth();
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::WithdrawalDelay, &delay);
+        publish_withdrawal_delay_set_event(&env, delay, env.ledger().timestamp());
+    }
+
+    fn get_withdrawal_delay(env: Env) -> u64 {
+        get_withdrawal_delay(&env)
+    }
+
+    fn queue_withdrawal(env: Env, token: Address, amount: i128, to: Address) -> u64 {
+        let merchant = get_merchant_address(&env);
+        merchant.require_auth();
+
+        if amount <= 0 {
+            panic_with_error!(&env, ContractError::InvalidAmount);
+        }
+        assert_within_approval_threshold(&env, &token, amount);
+
+        enqueue_withdrawal(&env, &token, amount, &to)
+    }
+
+    fn veto_withdrawal(env: Env, withdrawal_id: u64) {
+        let manager = get_manager(&env);
+        manager.require_auth();
+
+        let mut withdrawal = get_queued_withdrawal_record(&env, withdrawal_id);
+        if env.ledger().timestamp() >= withdrawal.unlocks_at {
+            panic_with_error!(&env, ContractError::VetoWindowClosed);
+        }
+        close_queued_withdrawal(&env, &mut withdrawal, WithdrawalStatus::Vetoed);
+        publish_withdrawal_vetoed_event(&env, withdrawal_id, manager, env.ledger().timestamp());
+    }
+
+    fn execute_queued_withdrawal(env: Env, withdrawal_id: u64) {
+        let merchant = get_merchant_address(&env);
+        merchant.require_auth();
+
+        let mut withdrawal = get_queued_withdrawal_record(&env, withdrawal_id);
+        if withdrawal.status == WithdrawalStatus::Pending
+            && env.ledger().timestamp() < withdrawal.unlocks_at
+        {
+            panic_with_error!(&env, ContractError::WithdrawalLocked);
+        }
+        close_queued_withdrawal(&env, &mut withdrawal, WithdrawalStatus::Executed);
+
+        transfer_out(&env, &withdrawal.token, withdrawal.amount, &withdrawal.to);
+        publish_timelock_executed_event(&env, withdrawal_id, env.ledger().timestamp());
+    }
+
+    fn cancel_queued_withdrawal(env: Env, withdrawal_id: u64) {
+        let merchant = get_merchant_address(&env);
+        merchant.require_auth();
+
+        let mut withdrawal = get_queued_withdrawal_record(&env, withdrawal_id);
+        close_queued_withdrawal(&env, &mut withdrawal, WithdrawalStatus::Cancelled);
+        publish_timelock_cancelled_event(&env, withdrawal_id, env.ledger().timestamp());
+    }
+
+    fn get_queued_withdrawal(env: Env, withdrawal_id: u64) -> QueuedWithdrawal {
+        get_queued_withdrawal_record(&env, withdrawal_id)
+    }
+
+    fn get_pending_withdrawals(env: Env) -> Vec<QueuedWithdrawal> {
+        let mut withdrawals = Vec::new(&env);
+        for withdrawal_id in get_pending_withdrawal_ids(&env).iter() {
+            withdrawals.push_back(get_queued_withdrawal_record(&env, withdrawal_id));
+        }
+        withdrawals
+    }
+
+    fn add_signer_key(env: Env, public_key: BytesN<32>, permissions: SignerPermissions) {
+        let merchant = get_merchant_address(&env);
+        merchant.require_auth();
+
+        for spend_limit in permissions.spend_limits.iter() {
+            if spend_limit.limit <= 0 {
+                panic_with_error!(&env, ContractError::InvalidAmount);
+            }
+        }
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::SignerKey(public_key.clone()), &permissions);
+        publish_signer_key_added_event(&env, public_key, env.ledger().timestamp());
+    }
+
+    fn remove_signer_key(env: Env, public_key: BytesN<32>) {
+        let merchant = get_merchant_address(&env);
+        merchant.require_auth();
+
+        let key = DataKey::SignerKey(public_key.clone());
+        if !env.storage().persistent().has(&key) {
+            panic_with_error!(&env, ContractError::SignerNotFound);
+        }
+        env.storage().persistent().remove(&key);
+        publish_signer_key_removed_event(&env, public_key, env.ledger().timestamp());
+    }
+
+    fn get_signer_key(env: Env, public_key: BytesN<32>) -> SignerPermissions {
+        get_signer_key_permissions(&env, &public_key)
+            .unwrap_or_else(|| panic_with_error!(&env, ContractError::SignerNotFound))
+    }
+
+    fn get_signer_key_spent(env: Env, public_key: BytesN<32>, token: Address) -> i128 {
+        sum_withdrawals(&get_recent_withdrawals(
+            &env,
+            &DataKey::SignerSpend(public_key, token),
+        ))
+    }
+
+    fn add_passkey(
+        env: Env,
+        public_key: BytesN<65>,
+        rp_id_hash: BytesN<32>,
+        permissions: SignerPermissions,
+    ) {
+        let merchant = get_merchant_address(&env);
+        merchant.require_auth();
+
+        for spend_limit in permissions.spend_limits.iter() {
+            if spend_limit.limit <= 0 {
+                panic_with_error!(&env, ContractError::InvalidAmount);
+            }
+        }
+
+        env.storage().persistent().set(
+            &DataKey::Passkey(public_key.clone()),
+            &PasskeySigner {
+                rp_id_hash,
+                permissions,
+            },
+        );
+        publish_passkey_added_event(&env, public_key, env.ledger().timestamp());
+    }
+
+    fn remove_passkey(env: Env, public_key: BytesN<65>) {
+        let merchant = get_merchant_address(&env);
+        merchant.require_auth();
+
+        let key = DataKey::Passkey(public_key.clone());
+        if !env.storage().persistent().has(&key) {
+            panic_with_error!(&env, ContractError::SignerNotFound);
+        }
+        env.storage().persistent().remove(&key);
+        publish_passkey_removed_event(&env, public_key, env.ledger().timestamp());
+    }
+
+    fn get_passkey(env: Env, public_key: BytesN<65>) -> PasskeySigner {
+        get_passkey_signer(&env, &public_key)
+            .unwrap_or_else(|| panic_with_error!(&env, ContractError::SignerNotFound))
+    }
+
+    fn get_passkey_spent(env: Env, public_key: BytesN<65>, token: Address) -> i128 {
+        sum_withdrawals(&get_recent_withdrawals(
+            &env,
+            &DataKey::SignerSpend(passkey_id(&env, &public_key), token),
+        ))
+    }
+}
+
+#[contractimpl]
+impl CustomAccountInterface for MerchantAccount {
+    type Signature = AccountSignature;
+    type Error = ContractError;
+
+    #[allow(non_snake_case)]
+    fn __check_auth(
+        env: Env,
+        signature_payload: Hash<32>,
+        signature: AccountSignature,
+        auth_contexts: Vec<Context>,
+    ) -> Result<(), ContractError> {
+        let (key_id, permissions) = match signature {
+            AccountSignature::Ed25519(signature) => {
+                let permissions = get_signer_key_permissions(&env, &signature.public_key)
+                    .ok_or(ContractError::SignerNotFound)?;
+                env.crypto().ed25519_verify(
+                    &signature.public_key,
+                    &signature_payload.into(),
+                    &signature.signature,
+                );
+                (signature.public_key, permissions)
+            }
+            AccountSignature::Secp256r1(signature) => {
+                let passkey = get_passkey_signer(&env, &signature.public_key)
+                    .ok_or(ContractError::SignerNotFound)?;
+                webauthn::verify(&env, &signature_payload, &signature, &passkey.rp_id_hash)?;
+                (passkey_id(&env, &signature.public_key), passkey.permissions)
+            }
+        };
+
+        for context in auth_contexts.iter() {
+            authorize_context(&env, &key_id, &permissions, &context)?;
+        }
+        Ok(())
+    }
 }
@@ -6,4 +6,26 @@ use soroban_sdk::contracterror;
 pub enum ContractError {
     AlreadyInitialized = 1,
     NotInitialized = 2,
+    InvalidAmount = 3,
+    InsufficientBalance = 4,
+    AccountNotVerified = 5,
+    PayoutAddressNotAllowed = 6,
+    WithdrawalLimitExceeded = 7,
+    ApprovalRequired = 8,
+    NotSigner = 9,
+    InvalidThreshold = 10,
+    ProposalNotFound = 11,
+    ProposalNotPending = 12,
+    ProposalExpired = 13,
+    AlreadyApproved = 14,
+    ThresholdNotMet = 15,
+    TimelockRequired = 16,
+    WithdrawalNotFound = 17,
+    WithdrawalNotPending = 18,
+    WithdrawalLocked = 19,
+    VetoWindowClosed = 20,
+    SignerNotFound = 21,
+    CallNotPermitted = 22,
+    SpendLimitExceeded = 23,
+    InvalidAssertion = 24,
 }
@@ -1,4 +1,4 @@
-use soroban_sdk::{contractevent, Address, Env};
+use soroban_sdk::{contractevent, Address, BytesN, Env, Vec};
 
 #[contractevent]
 pub struct AccountInitalizedEvent {
@@ -38,3 +38,376 @@ pub struct AccountVerified {
 pub fn publish_account_verified_event(env: &Env, timestamp: u64) {
     AccountVerified { timestamp }.publish(env);
 }
+
+#[contractevent]
+pub struct VerificationSetEvent {
+    pub verified: bool,
+    pub timestamp: u64,
+}
+
+pub fn publish_verification_set_event(env: &Env, verified: bool, timestamp: u64) {
+    VerificationSetEvent {
+        verified,
+        timestamp,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct WithdrawalEvent {
+    pub token: Address,
+    pub amount: i128,
+    pub to: Address,
+    pub timestamp: u64,
+}
+
+pub fn publish_withdrawal_event(
+    env: &Env,
+    token: Address,
+    amount: i128,
+    to: Address,
+    timestamp: u64,
+) {
+    WithdrawalEvent {
+        token,
+        amount,
+        to,
+        timestamp,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct RefundEvent {
+    pub token: Address,
+    pub amount: i128,
+    pub to: Address,
+    pub timestamp: u64,
+}
+
+pub fn publish_refund_event(env: &Env, token: Address, amount: i128, to: Address, timestamp: u64) {
+    RefundEvent {
+        token,
+        amount,
+        to,
+        timestamp,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct PayoutAllowlistSetEvent {
+    pub addresses: Vec<Address>,
+    pub timestamp: u64,
+}
+
+pub fn publish_payout_allowlist_set_event(env: &Env, addresses: Vec<Address>, timestamp: u64) {
+    PayoutAllowlistSetEvent {
+        addresses,
+        timestamp,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct WithdrawalLimitSetEvent {
+    pub token: Address,
+    pub limit: Option<i128>,
+    pub timestamp: u64,
+}
+
+pub fn publish_withdrawal_limit_set_event(
+    env: &Env,
+    token: Address,
+    limit: Option<i128>,
+    timestamp: u64,
+) {
+    WithdrawalLimitSetEvent {
+        token,
+        limit,
+        timestamp,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct SignersSetEvent {
+    pub signers: Vec<Address>,
+    pub threshold: u32,
+    pub timestamp: u64,
+}
+
+pub fn publish_signers_set_event(env: &Env, signers: Vec<Address>, threshold: u32, timestamp: u64) {
+    SignersSetEvent {
+        signers,
+        threshold,
+        timestamp,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct ApprovalThresholdSetEvent {
+    pub token: Address,
+    pub amount: Option<i128>,
+    pub timestamp: u64,
+}
+
+pub fn publish_approval_threshold_set_event(
+    env: &Env,
+    token: Address,
+    amount: Option<i128>,
+    timestamp: u64,
+) {
+    ApprovalThresholdSetEvent {
+        token,
+        amount,
+        timestamp,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct WithdrawalProposedEvent {
+    pub proposal_id: u64,
+    pub proposer: Address,
+    pub token: Address,
+    pub amount: i128,
+    pub to: Address,
+    pub expires_at: u64,
+    pub timestamp: u64,
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn publish_withdrawal_proposed_event(
+    env: &Env,
+    proposal_id: u64,
+    proposer: Address,
+    token: Address,
+    amount: i128,
+    to: Address,
+    expires_at: u64,
+    timestamp: u64,
+) {
+    WithdrawalProposedEvent {
+        proposal_id,
+        proposer,
+        token,
+        amount,
+        to,
+        expires_at,
+        timestamp,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct WithdrawalApprovedEvent {
+    pub proposal_id: u64,
+    pub signer: Address,
+    pub approvals: u32,
+    pub timestamp: u64,
+}
+
+pub fn publish_withdrawal_approved_event(
+    env: &Env,
+    proposal_id: u64,
+    signer: Address,
+    approvals: u32,
+    timestamp: u64,
+) {
+    WithdrawalApprovedEvent {
+        proposal_id,
+        signer,
+        approvals,
+        timestamp,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct WithdrawalExecutedEvent {
+    pub proposal_id: u64,
+    pub signer: Address,
+    pub timestamp: u64,
+}
+
+pub fn publish_withdrawal_executed_event(
+    env: &Env,
+    proposal_id: u64,
+    signer: Address,
+    timestamp: u64,
+) {
+    WithdrawalExecutedEvent {
+        proposal_id,
+        signer,
+        timestamp,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct WithdrawalCancelledEvent {
+    pub proposal_id: u64,
+    pub caller: Address,
+    pub timestamp: u64,
+}
+
+pub fn publish_withdrawal_cancelled_event(
+    env: &Env,
+    proposal_id: u64,
+    caller: Address,
+    timestamp: u64,
+) {
+    WithdrawalCancelledEvent {
+        proposal_id,
+        caller,
+        timestamp,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct WithdrawalDelaySetEvent {
+    pub delay: u64,
+    pub timestamp: u64,
+}
+
+pub fn publish_withdrawal_delay_set_event(env: &Env, delay: u64, timestamp: u64) {
+    WithdrawalDelaySetEvent { delay, timestamp }.publish(env);
+}
+
+#[contractevent]
+pub struct WithdrawalQueuedEvent {
+    pub withdrawal_id: u64,
+    pub token: Address,
+    pub amount: i128,
+    pub to: Address,
+    pub unlocks_at: u64,
+    pub timestamp: u64,
+}
+
+pub fn publish_withdrawal_queued_event(
+    env: &Env,
+    withdrawal_id: u64,
+    token: Address,
+    amount: i128,
+    to: Address,
+    unlocks_at: u64,
+    timestamp: u64,
+) {
+    WithdrawalQueuedEvent {
+        withdrawal_id,
+        token,
+        amount,
+        to,
+        unlocks_at,
+        timestamp,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct WithdrawalVetoedEvent {
+    pub withdrawal_id: u64,
+    pub manager: Address,
+    pub timestamp: u64,
+}
+
+pub fn publish_withdrawal_vetoed_event(
+    env: &Env,
+    withdrawal_id: u64,
+    manager: Address,
+    timestamp: u64,
+) {
+    WithdrawalVetoedEvent {
+        withdrawal_id,
+        manager,
+        timestamp,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct TimelockExecutedEvent {
+    pub withdrawal_id: u64,
+    pub timestamp: u64,
+}
+
+pub fn publish_timelock_executed_event(env: &Env, withdrawal_id: u64, timestamp: u64) {
+    TimelockExecutedEvent {
+        withdrawal_id,
+        timestamp,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct TimelockCancelledEvent {
+    pub withdrawal_id: u64,
+    pub timestamp: u64,
+}
+
+pub fn publish_timelock_cancelled_event(env: &Env, withdrawal_id: u64, timestamp: u64) {
+    TimelockCancelledEvent {
+        withdrawal_id,
+        timestamp,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct SignerKeyAddedEvent {
+    pub public_key: BytesN<32>,
+    pub timestamp: u64,
+}
+
+pub fn publish_signer_key_added_event(env: &Env, public_key: BytesN<32>, timestamp: u64) {
+    SignerKeyAddedEvent {
+        public_key,
+        timestamp,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct SignerKeyRemovedEvent {
+    pub public_key: BytesN<32>,
+    pub timestamp: u64,
+}
+
+pub fn publish_signer_key_removed_event(env: &Env, public_key: BytesN<32>, timestamp: u64) {
+    SignerKeyRemovedEvent {
+        public_key,
+        timestamp,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct PasskeyAddedEvent {
+    pub public_key: BytesN<65>,
+    pub timestamp: u64,
+}
+
+pub fn publish_passkey_added_event(env: &Env, public_key: BytesN<65>, timestamp: u64) {
+    PasskeyAddedEvent {
+        public_key,
+        timestamp,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct PasskeyRemovedEvent {
+    pub public_key: BytesN<65>,
+    pub timestamp: u64,
+}
+
+pub fn publish_passkey_removed_event(env: &Env, public_key: BytesN<65>, timestamp: u64) {
+    PasskeyRemovedEvent {
+        public_key,
+        timestamp,
+    }
+    .publish(env);
+}
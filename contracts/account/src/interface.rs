@@ -1,5 +1,7 @@
-use crate::types::TokenBalance;
-use soroban_sdk::{contracttrait, Address, Env, Vec};
+use crate::types::{
+    PasskeySigner, QueuedWithdrawal, SignerPermissions, TokenBalance, WithdrawalProposal,
+};
+use soroban_sdk::{contracttrait, Address, BytesN, Env, Vec};
 
 #[contracttrait]
 pub trait MerchantAccountTrait {
@@ -10,5 +12,55 @@ pub trait MerchantAccountTrait {
     fn get_balance(env: Env, token: Address) -> i128;
     fn get_balances(env: Env) -> Vec<TokenBalance>;
     fn verify_account(env: Env);
+    fn set_verified(env: Env, verified: bool);
     fn is_verified_account(env: Env) -> bool;
+    fn set_payout_allowlist(env: Env, addresses: Vec<Address>);
+    fn get_payout_allowlist(env: Env) -> Vec<Address>;
+    fn set_withdrawal_limit(env: Env, token: Address, limit: Option<i128>);
+    fn get_withdrawal_limit(env: Env, token: Address) -> Option<i128>;
+    fn get_withdrawn_in_window(env: Env, token: Address) -> i128;
+    fn withdraw(env: Env, token: Address, amount: i128, to: Address);
+    fn refund(env: Env, token: Address, to: Address, amount: i128);
+    fn set_signers(env: Env, approvers: Vec<Address>, signers: Vec<Address>, threshold: u32);
+    fn get_signers(env: Env) -> Vec<Address>;
+    fn get_signer_threshold(env: Env) -> u32;
+    fn set_approval_threshold(
+        env: Env,
+        approvers: Vec<Address>,
+        token: Address,
+        amount: Option<i128>,
+    );
+    fn get_approval_threshold(env: Env, token: Address) -> Option<i128>;
+    fn propose_withdrawal(
+        env: Env,
+        proposer: Address,
+        token: Address,
+        amount: i128,
+        to: Address,
+    ) -> u64;
+    fn approve_withdrawal(env: Env, signer: Address, proposal_id: u64);
+    fn execute_withdrawal(env: Env, signer: Address, proposal_id: u64);
+    fn cancel_withdrawal(env: Env, caller: Address, proposal_id: u64);
+    fn get_proposal(env: Env, proposal_id: u64) -> WithdrawalProposal;
+    fn set_withdrawal_delay(env: Env, delay: u64);
+    fn get_withdrawal_delay(env: Env) -> u64;
+    fn queue_withdrawal(env: Env, token: Address, amount: i128, to: Address) -> u64;
+    fn veto_withdrawal(env: Env, withdrawal_id: u64);
+    fn execute_queued_withdrawal(env: Env, withdrawal_id: u64);
+    fn cancel_queued_withdrawal(env: Env, withdrawal_id: u64);
+    fn get_queued_withdrawal(env: Env, withdrawal_id: u64) -> QueuedWithdrawal;
+    fn get_pending_withdrawals(env: Env) -> Vec<QueuedWithdrawal>;
+    fn add_signer_key(env: Env, public_key: BytesN<32>, permissions: SignerPermissions);
+    fn remove_signer_key(env: Env, public_key: BytesN<32>);
+    fn get_signer_key(env: Env, public_key: BytesN<32>) -> SignerPermissions;
+    fn get_signer_key_spent(env: Env, public_key: BytesN<32>, token: Address) -> i128;
+    fn add_passkey(
+        env: Env,
+        public_key: BytesN<65>,
+        rp_id_hash: BytesN<32>,
+        permissions: SignerPermissions,
+    );
+    fn remove_passkey(env: Env, public_key: BytesN<65>);
+    fn get_passkey(env: Env, public_key: BytesN<65>) -> PasskeySigner;
+    fn get_passkey_spent(env: Env, public_key: BytesN<65>, token: Address) -> i128;
 }
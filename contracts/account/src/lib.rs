@@ -4,6 +4,7 @@ pub mod errors;
 pub mod events;
 pub mod interface;
 pub mod types;
+pub mod webauthn;
 
 #[cfg(test)]
 pub mod tests;
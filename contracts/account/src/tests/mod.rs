@@ -1,2 +1,7 @@
 pub mod test;
+pub mod test_custom_account;
+pub mod test_multisig;
+pub mod test_passkey;
+pub mod test_timelock;
 pub mod test_token_balance;
+pub mod test_withdraw;
@@ -54,20 +54,35 @@ fn test_verify_account() {
     let merchant_id = 1;
     client.initialize(&merchant, &manager, &merchant_id);
 
-    assert_eq!(client.is_verified_account(), false);
+    assert!(!client.is_verified_account());
 
     client.verify_account();
     let events = env.events().all();
 
-    assert_eq!(client.is_verified_account(), true);
+    assert!(client.is_verified_account());
 
     assert!(
-        events.len() > 0,
+        !events.is_empty(),
         "No events captured immediately after verify_account!"
     );
     let (_event_contract_id, _topics, _data) = events.get(events.len() - 1).unwrap();
 }
 
+#[test]
+fn test_set_verified_revokes_verification() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(MerchantAccount, ());
+    let client = MerchantAccountClient::new(&env, &contract_id);
+
+    client.initialize(&Address::generate(&env), &Address::generate(&env), &1);
+    client.set_verified(&true);
+    assert!(client.is_verified_account());
+
+    client.set_verified(&false);
+    assert!(!client.is_verified_account());
+}
+
 #[should_panic(expected = "HostError: Error(Auth, InvalidAction)")]
 #[test]
 fn test_verify_account_unauthorized() {
@@ -0,0 +1,472 @@
+#![cfg(test)]
+
+use crate::account::{MerchantAccount, MerchantAccountClient};
+use crate::errors::ContractError;
+use crate::types::{
+    AccountSignature, CallPermission, Ed25519Signature, SignerPermissions, SpendLimit,
+};
+use ed25519_dalek::{Signer, SigningKey};
+use soroban_sdk::auth::{Context, ContractContext};
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{vec, Address, BytesN, Env, IntoVal, Symbol, Val, Vec};
+
+const DAY: u64 = 24 * 60 * 60;
+
+fn setup_account(env: &Env) -> (Address, MerchantAccountClient<'_>) {
+    let contract_id = env.register(MerchantAccount, ());
+    let client = MerchantAccountClient::new(env, &contract_id);
+    client.initialize(&Address::generate(env), &Address::generate(env), &1);
+    client.verify_account();
+    (contract_id, client)
+}
+
+fn public_key(env: &Env, signing_key: &SigningKey) -> BytesN<32> {
+    BytesN::from_array(env, &signing_key.verifying_key().to_bytes())
+}
+
+fn sign(env: &Env, signing_key: &SigningKey, payload: &BytesN<32>) -> Val {
+    AccountSignature::Ed25519(Ed25519Signature {
+        public_key: public_key(env, signing_key),
+        signature: BytesN::from_array(env, &signing_key.sign(&payload.to_array()).to_bytes()),
+    })
+    .into_val(env)
+}
+
+fn call(env: &Env, contract: &Address, fn_name: &str, args: Vec<Val>) -> Context {
+    Context::Contract(ContractContext {
+        contract: contract.clone(),
+        fn_name: Symbol::new(env, fn_name),
+        args,
+    })
+}
+
+fn transfer(env: &Env, account: &Address, token: &Address, amount: i128) -> Context {
+    transfer_to(env, account, token, &Address::generate(env), amount)
+}
+
+fn transfer_to(
+    env: &Env,
+    account: &Address,
+    token: &Address,
+    to: &Address,
+    amount: i128,
+) -> Context {
+    call(
+        env,
+        token,
+        "transfer",
+        vec![
+            env,
+            account.into_val(env),
+            to.into_val(env),
+            amount.into_val(env),
+        ],
+    )
+}
+
+fn check_auth(
+    env: &Env,
+    account: &Address,
+    signing_key: &SigningKey,
+    contexts: Vec<Context>,
+) -> Result<(), Result<ContractError, soroban_sdk::InvokeError>> {
+    let payload = BytesN::from_array(env, &[1u8; 32]);
+    env.try_invoke_contract_check_auth::<ContractError>(
+        account,
+        &payload,
+        sign(env, signing_key, &payload),
+        &contexts,
+    )
+}
+
+fn scoped_permissions(env: &Env, shade: &Address, token: &Address) -> SignerPermissions {
+    SignerPermissions {
+        allowed_calls: vec![
+            env,
+            CallPermission {
+                contract: shade.clone(),
+                function: Some(Symbol::new(env, "create_invoice")),
+            },
+        ],
+        spend_limits: vec![
+            env,
+            SpendLimit {
+                token: token.clone(),
+                limit: 500,
+            },
+        ],
+    }
+}
+
+#[test]
+fn test_scoped_key_can_call_permitted_function() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (account, client) = setup_account(&env);
+    let (shade, token) = (Address::generate(&env), Address::generate(&env));
+
+    let hot_key = SigningKey::from_bytes(&[3u8; 32]);
+    client.add_signer_key(
+        &public_key(&env, &hot_key),
+        &scoped_permissions(&env, &shade, &token),
+    );
+
+    let create_invoice = call(&env, &shade, "create_invoice", Vec::new(&env));
+    assert_eq!(
+        check_auth(&env, &account, &hot_key, vec![&env, create_invoice]),
+        Ok(())
+    );
+
+    let refund = call(&env, &shade, "refund_invoice", Vec::new(&env));
+    assert_eq!(
+        check_auth(&env, &account, &hot_key, vec![&env, refund]),
+        Err(Ok(ContractError::CallNotPermitted))
+    );
+}
+
+#[test]
+fn test_transfer_limited_by_spend_limit() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (account, client) = setup_account(&env);
+    let (shade, token) = (Address::generate(&env), Address::generate(&env));
+
+    let hot_key = SigningKey::from_bytes(&[3u8; 32]);
+    let key = public_key(&env, &hot_key);
+    client.add_signer_key(&key, &scoped_permissions(&env, &shade, &token));
+
+    let first = transfer(&env, &account, &token, 300);
+    assert_eq!(
+        check_auth(&env, &account, &hot_key, vec![&env, first]),
+        Ok(())
+    );
+    assert_eq!(client.get_signer_key_spent(&key, &token), 300);
+
+    let second = transfer(&env, &account, &token, 300);
+    assert_eq!(
+        check_auth(&env, &account, &hot_key, vec![&env, second.clone()]),
+        Err(Ok(ContractError::SpendLimitExceeded))
+    );
+
+    env.ledger().with_mut(|li| li.timestamp += DAY);
+    assert_eq!(
+        check_auth(&env, &account, &hot_key, vec![&env, second]),
+        Ok(())
+    );
+}
+
+#[test]
+fn test_key_without_spend_limit_cannot_move_funds() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (account, client) = setup_account(&env);
+    let (shade, token) = (Address::generate(&env), Address::generate(&env));
+    let other_token = Address::generate(&env);
+
+    let hot_key = SigningKey::from_bytes(&[3u8; 32]);
+    client.add_signer_key(
+        &public_key(&env, &hot_key),
+        &SignerPermissions {
+            allowed_calls: vec![
+                &env,
+                CallPermission {
+                    contract: other_token.clone(),
+                    function: None,
+                },
+            ],
+            spend_limits: scoped_permissions(&env, &shade, &token).spend_limits,
+        },
+    );
+
+    let untracked = transfer(&env, &account, &other_token, 1);
+    assert_eq!(
+        check_auth(&env, &account, &hot_key, vec![&env, untracked]),
+        Err(Ok(ContractError::CallNotPermitted))
+    );
+
+    let approve = call(&env, &other_token, "approve", Vec::new(&env));
+    assert_eq!(
+        check_auth(&env, &account, &hot_key, vec![&env, approve]),
+        Err(Ok(ContractError::CallNotPermitted))
+    );
+}
+
+#[test]
+fn test_wildcard_does_not_cover_account_admin_functions() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (account, client) = setup_account(&env);
+
+    let hot_key = SigningKey::from_bytes(&[3u8; 32]);
+    client.add_signer_key(
+        &public_key(&env, &hot_key),
+        &SignerPermissions {
+            allowed_calls: vec![
+                &env,
+                CallPermission {
+                    contract: account.clone(),
+                    function: None,
+                },
+                CallPermission {
+                    contract: account.clone(),
+                    function: Some(Symbol::new(&env, "withdraw")),
+                },
+            ],
+            spend_limits: Vec::new(&env),
+        },
+    );
+
+    let withdraw = call(&env, &account, "withdraw", Vec::new(&env));
+    assert_eq!(
+        check_auth(&env, &account, &hot_key, vec![&env, withdraw]),
+        Ok(())
+    );
+
+    for fn_name in ["add_signer_key", "add_passkey", "set_signers"] {
+        let admin_call = call(&env, &account, fn_name, Vec::new(&env));
+        assert_eq!(
+            check_auth(&env, &account, &hot_key, vec![&env, admin_call]),
+            Err(Ok(ContractError::CallNotPermitted))
+        );
+    }
+}
+
+#[test]
+fn test_transfer_from_and_burn_count_against_spend_limit() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (account, client) = setup_account(&env);
+    let (shade, token) = (Address::generate(&env), Address::generate(&env));
+
+    let hot_key = SigningKey::from_bytes(&[3u8; 32]);
+    let key = public_key(&env, &hot_key);
+    let mut permissions = scoped_permissions(&env, &shade, &token);
+    permissions.allowed_calls.push_back(CallPermission {
+        contract: token.clone(),
+        function: None,
+    });
+    client.add_signer_key(&key, &permissions);
+
+    let transfer_from = call(
+        &env,
+        &token,
+        "transfer_from",
+        vec![
+            &env,
+            account.into_val(&env),
+            Address::generate(&env).into_val(&env),
+            Address::generate(&env).into_val(&env),
+            200_i128.into_val(&env),
+        ],
+    );
+    assert_eq!(
+        check_auth(&env, &account, &hot_key, vec![&env, transfer_from]),
+        Ok(())
+    );
+
+    let burn = call(
+        &env,
+        &token,
+        "burn",
+        vec![&env, account.into_val(&env), 200_i128.into_val(&env)],
+    );
+    assert_eq!(
+        check_auth(&env, &account, &hot_key, vec![&env, burn]),
+        Ok(())
+    );
+    assert_eq!(client.get_signer_key_spent(&key, &token), 400);
+
+    let burn_from = call(
+        &env,
+        &token,
+        "burn_from",
+        vec![
+            &env,
+            account.into_val(&env),
+            Address::generate(&env).into_val(&env),
+            200_i128.into_val(&env),
+        ],
+    );
+    assert_eq!(
+        check_auth(&env, &account, &hot_key, vec![&env, burn_from]),
+        Err(Ok(ContractError::SpendLimitExceeded))
+    );
+}
+
+#[test]
+fn test_transfer_requires_verified_account() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (account, client) = setup_account(&env);
+    let (shade, token) = (Address::generate(&env), Address::generate(&env));
+    client.set_verified(&false);
+
+    let hot_key = SigningKey::from_bytes(&[3u8; 32]);
+    let key = public_key(&env, &hot_key);
+    client.add_signer_key(&key, &scoped_permissions(&env, &shade, &token));
+
+    let spend = transfer(&env, &account, &token, 100);
+    assert_eq!(
+        check_auth(&env, &account, &hot_key, vec![&env, spend]),
+        Err(Ok(ContractError::AccountNotVerified))
+    );
+    assert_eq!(client.get_signer_key_spent(&key, &token), 0);
+}
+
+#[test]
+fn test_transfer_respects_payout_allowlist() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (account, client) = setup_account(&env);
+    let (shade, token) = (Address::generate(&env), Address::generate(&env));
+    let payout = Address::generate(&env);
+    client.set_payout_allowlist(&vec![&env, payout.clone()]);
+
+    let hot_key = SigningKey::from_bytes(&[3u8; 32]);
+    client.add_signer_key(
+        &public_key(&env, &hot_key),
+        &scoped_permissions(&env, &shade, &token),
+    );
+
+    let elsewhere = transfer(&env, &account, &token, 100);
+    assert_eq!(
+        check_auth(&env, &account, &hot_key, vec![&env, elsewhere]),
+        Err(Ok(ContractError::PayoutAddressNotAllowed))
+    );
+
+    let burn = call(
+        &env,
+        &token,
+        "burn",
+        vec![&env, account.into_val(&env), 100_i128.into_val(&env)],
+    );
+    assert_eq!(
+        check_auth(&env, &account, &hot_key, vec![&env, burn]),
+        Err(Ok(ContractError::PayoutAddressNotAllowed))
+    );
+
+    let allowed = transfer_to(&env, &account, &token, &payout, 100);
+    assert_eq!(
+        check_auth(&env, &account, &hot_key, vec![&env, allowed]),
+        Ok(())
+    );
+}
+
+#[test]
+fn test_transfer_counts_against_withdrawal_limit() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (account, client) = setup_account(&env);
+    let (shade, token) = (Address::generate(&env), Address::generate(&env));
+    client.set_withdrawal_limit(&token, &Some(400));
+
+    let hot_key = SigningKey::from_bytes(&[3u8; 32]);
+    client.add_signer_key(
+        &public_key(&env, &hot_key),
+        &scoped_permissions(&env, &shade, &token),
+    );
+
+    let first = transfer(&env, &account, &token, 300);
+    assert_eq!(
+        check_auth(&env, &account, &hot_key, vec![&env, first]),
+        Ok(())
+    );
+    assert_eq!(client.get_withdrawn_in_window(&token), 300);
+
+    let second = transfer(&env, &account, &token, 150);
+    assert_eq!(
+        check_auth(&env, &account, &hot_key, vec![&env, second]),
+        Err(Ok(ContractError::WithdrawalLimitExceeded))
+    );
+}
+
+#[test]
+fn test_transfer_blocked_while_timelock_is_set() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (account, client) = setup_account(&env);
+    let (shade, token) = (Address::generate(&env), Address::generate(&env));
+    client.set_withdrawal_delay(&DAY);
+
+    let hot_key = SigningKey::from_bytes(&[3u8; 32]);
+    client.add_signer_key(
+        &public_key(&env, &hot_key),
+        &scoped_permissions(&env, &shade, &token),
+    );
+
+    let spend = transfer(&env, &account, &token, 100);
+    assert_eq!(
+        check_auth(&env, &account, &hot_key, vec![&env, spend]),
+        Err(Ok(ContractError::TimelockRequired))
+    );
+}
+
+#[test]
+fn test_transfer_above_approval_threshold_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (account, client) = setup_account(&env);
+    let (shade, token) = (Address::generate(&env), Address::generate(&env));
+    client.set_approval_threshold(&Vec::new(&env), &token, &Some(100));
+
+    let hot_key = SigningKey::from_bytes(&[3u8; 32]);
+    client.add_signer_key(
+        &public_key(&env, &hot_key),
+        &scoped_permissions(&env, &shade, &token),
+    );
+
+    let spend = transfer(&env, &account, &token, 200);
+    assert_eq!(
+        check_auth(&env, &account, &hot_key, vec![&env, spend]),
+        Err(Ok(ContractError::ApprovalRequired))
+    );
+}
+
+#[test]
+fn test_unknown_or_removed_key_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (account, client) = setup_account(&env);
+    let (shade, token) = (Address::generate(&env), Address::generate(&env));
+
+    let hot_key = SigningKey::from_bytes(&[3u8; 32]);
+    let create_invoice = call(&env, &shade, "create_invoice", Vec::new(&env));
+    assert_eq!(
+        check_auth(&env, &account, &hot_key, vec![&env, create_invoice.clone()]),
+        Err(Ok(ContractError::SignerNotFound))
+    );
+
+    let key = public_key(&env, &hot_key);
+    client.add_signer_key(&key, &scoped_permissions(&env, &shade, &token));
+    assert_eq!(client.get_signer_key(&key).spend_limits.len(), 1);
+    client.remove_signer_key(&key);
+
+    assert_eq!(
+        check_auth(&env, &account, &hot_key, vec![&env, create_invoice]),
+        Err(Ok(ContractError::SignerNotFound))
+    );
+}
+
+#[test]
+fn test_forged_signature_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (account, client) = setup_account(&env);
+    let (shade, token) = (Address::generate(&env), Address::generate(&env));
+
+    let hot_key = SigningKey::from_bytes(&[3u8; 32]);
+    client.add_signer_key(
+        &public_key(&env, &hot_key),
+        &scoped_permissions(&env, &shade, &token),
+    );
+
+    let payload = BytesN::from_array(&env, &[1u8; 32]);
+    let other_payload = BytesN::from_array(&env, &[2u8; 32]);
+    let result = env.try_invoke_contract_check_auth::<ContractError>(
+        &account,
+        &payload,
+        sign(&env, &hot_key, &other_payload),
+        &vec![&env, call(&env, &shade, "create_invoice", Vec::new(&env))],
+    );
+    assert!(result.is_err());
+}
@@ -0,0 +1,294 @@
+#![cfg(test)]
+
+use crate::account::{MerchantAccount, MerchantAccountClient, PROPOSAL_LIFETIME};
+use crate::types::ProposalStatus;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{token, vec, Address, Env, Vec};
+
+struct Setup<'a> {
+    client: MerchantAccountClient<'a>,
+    merchant: Address,
+    token: Address,
+    officers: [Address; 3],
+}
+
+fn setup_multisig_account(env: &Env) -> Setup<'_> {
+    let contract_id = env.register(MerchantAccount, ());
+    let client = MerchantAccountClient::new(env, &contract_id);
+
+    let merchant = Address::generate(env);
+    client.initialize(&merchant, &Address::generate(env), &1);
+    client.verify_account();
+
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    token::StellarAssetClient::new(env, &token).mint(&contract_id, &10_000);
+
+    let officers = [
+        Address::generate(env),
+        Address::generate(env),
+        Address::generate(env),
+    ];
+    client.set_signers(
+        &Vec::new(env),
+        &vec![
+            env,
+            officers[0].clone(),
+            officers[1].clone(),
+            officers[2].clone(),
+        ],
+        &2,
+    );
+    client.set_approval_threshold(
+        &vec![env, officers[0].clone(), officers[1].clone()],
+        &token,
+        &Some(1_000),
+    );
+
+    Setup {
+        client,
+        merchant,
+        token,
+        officers,
+    }
+}
+
+#[test]
+fn test_proposal_executes_once_threshold_reached() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup_multisig_account(&env);
+    let treasury = Address::generate(&env);
+
+    let proposal_id = s
+        .client
+        .propose_withdrawal(&s.officers[0], &s.token, &5_000, &treasury);
+    assert!(s
+        .client
+        .try_execute_withdrawal(&s.officers[0], &proposal_id)
+        .is_err());
+
+    s.client.approve_withdrawal(&s.officers[1], &proposal_id);
+    let proposal = s.client.get_proposal(&proposal_id);
+    assert_eq!(
+        proposal.approvals,
+        vec![&env, s.officers[0].clone(), s.officers[1].clone()]
+    );
+    assert_eq!(proposal.status, ProposalStatus::Pending);
+
+    s.client.execute_withdrawal(&s.officers[2], &proposal_id);
+    assert_eq!(
+        s.client.get_proposal(&proposal_id).status,
+        ProposalStatus::Executed
+    );
+    assert_eq!(token::Client::new(&env, &s.token).balance(&treasury), 5_000);
+}
+
+#[test]
+fn test_small_withdrawal_skips_approval() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup_multisig_account(&env);
+
+    s.client.withdraw(&s.token, &1_000, &s.merchant);
+    assert_eq!(s.client.get_signer_threshold(), 2);
+    assert_eq!(s.client.get_signers().len(), 3);
+}
+
+#[should_panic(expected = "HostError: Error(Contract, #8)")]
+#[test]
+fn test_large_withdrawal_requires_proposal() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup_multisig_account(&env);
+
+    s.client.withdraw(&s.token, &1_001, &s.merchant);
+}
+
+#[test]
+fn test_split_withdrawals_count_toward_approval_threshold() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup_multisig_account(&env);
+
+    s.client.withdraw(&s.token, &600, &s.merchant);
+    assert!(s.client.try_withdraw(&s.token, &600, &s.merchant).is_err());
+    s.client.withdraw(&s.token, &400, &s.merchant);
+    assert!(s.client.try_withdraw(&s.token, &1, &s.merchant).is_err());
+
+    env.ledger().with_mut(|li| li.timestamp += 24 * 60 * 60);
+    s.client.withdraw(&s.token, &1_000, &s.merchant);
+}
+
+#[test]
+fn test_split_queued_withdrawals_count_toward_approval_threshold() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup_multisig_account(&env);
+    s.client.set_withdrawal_delay(&(48 * 60 * 60));
+
+    s.client.queue_withdrawal(&s.token, &700, &s.merchant);
+    assert!(s
+        .client
+        .try_queue_withdrawal(&s.token, &700, &s.merchant)
+        .is_err());
+}
+
+#[should_panic(expected = "HostError: Error(Contract, #14)")]
+#[test]
+fn test_signer_cannot_approve_twice() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup_multisig_account(&env);
+
+    let proposal_id = s
+        .client
+        .propose_withdrawal(&s.officers[0], &s.token, &5_000, &s.merchant);
+    s.client.approve_withdrawal(&s.officers[0], &proposal_id);
+}
+
+#[should_panic(expected = "HostError: Error(Contract, #9)")]
+#[test]
+fn test_non_signer_cannot_propose() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup_multisig_account(&env);
+
+    s.client
+        .propose_withdrawal(&Address::generate(&env), &s.token, &5_000, &s.merchant);
+}
+
+#[should_panic(expected = "HostError: Error(Contract, #13)")]
+#[test]
+fn test_expired_proposal_cannot_be_approved() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup_multisig_account(&env);
+
+    let proposal_id = s
+        .client
+        .propose_withdrawal(&s.officers[0], &s.token, &5_000, &s.merchant);
+    env.ledger()
+        .with_mut(|li| li.timestamp += PROPOSAL_LIFETIME);
+    s.client.approve_withdrawal(&s.officers[1], &proposal_id);
+}
+
+#[should_panic(expected = "HostError: Error(Contract, #12)")]
+#[test]
+fn test_cancelled_proposal_cannot_execute() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup_multisig_account(&env);
+
+    let proposal_id = s
+        .client
+        .propose_withdrawal(&s.officers[0], &s.token, &5_000, &s.merchant);
+    s.client.approve_withdrawal(&s.officers[1], &proposal_id);
+    s.client.cancel_withdrawal(&s.merchant, &proposal_id);
+    assert_eq!(
+        s.client.get_proposal(&proposal_id).status,
+        ProposalStatus::Cancelled
+    );
+
+    s.client.execute_withdrawal(&s.officers[0], &proposal_id);
+}
+
+#[should_panic(expected = "HostError: Error(Contract, #10)")]
+#[test]
+fn test_threshold_above_signer_count() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup_multisig_account(&env);
+
+    s.client.set_signers(
+        &vec![&env, s.officers[0].clone(), s.officers[1].clone()],
+        &vec![&env, s.officers[0].clone()],
+        &2,
+    );
+}
+
+#[test]
+fn test_quorum_rotates_signers() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup_multisig_account(&env);
+    let new_officer = Address::generate(&env);
+
+    s.client.set_signers(
+        &vec![&env, s.officers[1].clone(), s.officers[2].clone()],
+        &vec![&env, s.officers[0].clone(), new_officer.clone()],
+        &2,
+    );
+    let mut authorizers = Vec::new(&env);
+    for (address, _invocation) in env.auths() {
+        authorizers.push_back(address);
+    }
+    assert!(authorizers.contains(&s.merchant));
+    assert!(authorizers.contains(&s.officers[1]));
+    assert!(authorizers.contains(&s.officers[2]));
+    assert_eq!(
+        s.client.get_signers(),
+        vec![&env, s.officers[0].clone(), new_officer]
+    );
+}
+
+#[should_panic(expected = "HostError: Error(Contract, #15)")]
+#[test]
+fn test_lone_merchant_cannot_replace_signers() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup_multisig_account(&env);
+
+    s.client
+        .set_signers(&Vec::new(&env), &vec![&env, s.merchant.clone()], &1);
+}
+
+#[should_panic(expected = "HostError: Error(Contract, #15)")]
+#[test]
+fn test_single_signer_cannot_lift_approval_threshold() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup_multisig_account(&env);
+
+    s.client
+        .set_approval_threshold(&vec![&env, s.officers[0].clone()], &s.token, &None);
+}
+
+#[should_panic(expected = "HostError: Error(Contract, #9)")]
+#[test]
+fn test_policy_approval_by_non_signer() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup_multisig_account(&env);
+
+    s.client.set_approval_threshold(
+        &vec![&env, s.officers[0].clone(), s.merchant.clone()],
+        &s.token,
+        &None,
+    );
+}
+
+#[should_panic(expected = "HostError: Error(Contract, #14)")]
+#[test]
+fn test_policy_approval_counts_each_signer_once() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup_multisig_account(&env);
+
+    s.client.set_approval_threshold(
+        &vec![&env, s.officers[0].clone(), s.officers[0].clone()],
+        &s.token,
+        &None,
+    );
+}
+
+#[should_panic(expected = "HostError: Error(Contract, #11)")]
+#[test]
+fn test_get_unknown_proposal() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup_multisig_account(&env);
+
+    s.client.get_proposal(&7);
+}
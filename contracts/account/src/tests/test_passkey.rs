@@ -0,0 +1,685 @@
+#![cfg(test)]
+
+use crate::account::{MerchantAccount, MerchantAccountClient};
+use crate::errors::ContractError;
+use crate::types::{
+    AccountSignature, CallPermission, SignerPermissions, SpendLimit, WebAuthnSignature,
+};
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use p256::ecdsa::{signature::Signer, Signature, SigningKey};
+use soroban_sdk::auth::{Context, ContractContext};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::xdr::{
+    HashIdPreimage, HashIdPreimageSorobanAuthorization, InvokeContractArgs, Limits, ScVal,
+    SorobanAddressCredentials, SorobanAuthorizationEntry, SorobanAuthorizedFunction,
+    SorobanAuthorizedInvocation, SorobanCredentials, WriteXdr,
+};
+use soroban_sdk::{token, vec, Address, Bytes, BytesN, Env, IntoVal, Symbol, TryFromVal, Val, Vec};
+
+const FLAG_USER_PRESENT: u8 = 0x01;
+
+fn setup_account(env: &Env) -> (Address, MerchantAccountClient<'_>) {
+    let contract_id = env.register(MerchantAccount, ());
+    let client = MerchantAccountClient::new(env, &contract_id);
+    client.initialize(&Address::generate(env), &Address::generate(env), &1);
+    client.verify_account();
+    (contract_id, client)
+}
+
+fn passkey() -> SigningKey {
+    SigningKey::from_slice(&[7u8; 32]).unwrap()
+}
+
+fn rp_id_hash(env: &Env) -> BytesN<32> {
+    BytesN::from_array(env, &[9u8; 32])
+}
+
+fn public_key(env: &Env, signing_key: &SigningKey) -> BytesN<65> {
+    let point = signing_key.verifying_key().to_encoded_point(false);
+    BytesN::from_array(env, point.as_bytes().try_into().unwrap())
+}
+
+fn client_data_json(env: &Env, kind: &str, challenge: &BytesN<32>) -> Bytes {
+    let mut encoded = [0u8; 43];
+    URL_SAFE_NO_PAD
+        .encode_slice(challenge.to_array(), &mut encoded)
+        .unwrap();
+
+    let mut json = Bytes::from_slice(env, b"{\"type\":\"");
+    json.extend_from_slice(kind.as_bytes());
+    json.extend_from_slice(b"\",\"challenge\":\"");
+    json.extend_from_slice(&encoded);
+    json.extend_from_slice(b"\",\"origin\":\"https://pay.shade.example\",\"crossOrigin\":false}");
+    json
+}
+
+fn authenticator_data(env: &Env, flags: u8) -> Bytes {
+    let mut data = [0u8; 37];
+    data[..32].copy_from_slice(&[9u8; 32]);
+    data[32] = flags;
+    data[36] = 1;
+    Bytes::from_slice(env, &data)
+}
+
+fn assertion(
+    env: &Env,
+    signing_key: &SigningKey,
+    authenticator_data: Bytes,
+    client_data_json: Bytes,
+) -> Val {
+    let mut message = authenticator_data.clone();
+    message.extend_from_array(&env.crypto().sha256(&client_data_json).to_array());
+    let mut buffer = [0u8; 256];
+    let message_slice = &mut buffer[..message.len() as usize];
+    message.copy_into_slice(message_slice);
+
+    let signature: Signature = signing_key.sign(message_slice);
+    let signature = signature.normalize_s().unwrap_or(signature);
+
+    AccountSignature::Secp256r1(WebAuthnSignature {
+        public_key: public_key(env, signing_key),
+        authenticator_data,
+        client_data_json,
+        signature: BytesN::from_array(env, &signature.to_bytes().into()),
+    })
+    .into_val(env)
+}
+
+fn call(env: &Env, contract: &Address, fn_name: &str) -> Context {
+    Context::Contract(ContractContext {
+        contract: contract.clone(),
+        fn_name: Symbol::new(env, fn_name),
+        args: Vec::new(env),
+    })
+}
+
+fn transfer(env: &Env, account: &Address, token: &Address, to: &Address, amount: i128) -> Context {
+    Context::Contract(ContractContext {
+        contract: token.clone(),
+        fn_name: Symbol::new(env, "transfer"),
+        args: vec![
+            env,
+            account.into_val(env),
+            to.into_val(env),
+            amount.into_val(env),
+        ],
+    })
+}
+
+fn check_auth(
+    env: &Env,
+    account: &Address,
+    signature: Val,
+    contexts: Vec<Context>,
+) -> Result<(), Result<ContractError, soroban_sdk::InvokeError>> {
+    env.try_invoke_contract_check_auth::<ContractError>(
+        account,
+        &BytesN::from_array(env, &[1u8; 32]),
+        signature,
+        &contexts,
+    )
+}
+
+fn signed(env: &Env, signing_key: &SigningKey) -> Val {
+    let payload = BytesN::from_array(env, &[1u8; 32]);
+    assertion(
+        env,
+        signing_key,
+        authenticator_data(env, FLAG_USER_PRESENT),
+        client_data_json(env, "webauthn.get", &payload),
+    )
+}
+
+fn account_permissions(env: &Env, account: &Address) -> SignerPermissions {
+    SignerPermissions {
+        allowed_calls: vec![
+            env,
+            CallPermission {
+                contract: account.clone(),
+                function: Some(Symbol::new(env, "withdraw")),
+            },
+            CallPermission {
+                contract: account.clone(),
+                function: Some(Symbol::new(env, "set_payout_allowlist")),
+            },
+        ],
+        spend_limits: Vec::new(env),
+    }
+}
+
+fn spend_permissions(env: &Env, token: &Address) -> SignerPermissions {
+    SignerPermissions {
+        allowed_calls: Vec::new(env),
+        spend_limits: vec![
+            env,
+            SpendLimit {
+                token: token.clone(),
+                limit: 500,
+            },
+        ],
+    }
+}
+
+// Builds a real authorization entry for `token.transfer(account, to, amount)`
+// signed by the passkey, so the host runs __check_auth itself.
+fn authorize_transfer(
+    env: &Env,
+    account: &Address,
+    signing_key: &SigningKey,
+    token: &Address,
+    to: &Address,
+    amount: i128,
+    nonce: i64,
+) -> SorobanAuthorizationEntry {
+    let args: Vec<Val> = (account.clone(), to.clone(), amount).into_val(env);
+    let xdr_args: [ScVal; 3] =
+        core::array::from_fn(|i| ScVal::try_from_val(env, &args.get(i as u32).unwrap()).unwrap());
+    let invocation = SorobanAuthorizedInvocation {
+        function: SorobanAuthorizedFunction::ContractFn(InvokeContractArgs {
+            contract_address: token.into(),
+            function_name: "transfer".try_into().unwrap(),
+            args: xdr_args.try_into().unwrap(),
+        }),
+        sub_invocations: Default::default(),
+    };
+
+    let signature_expiration_ledger = env.ledger().sequence() + 100;
+    let preimage = HashIdPreimage::SorobanAuthorization(HashIdPreimageSorobanAuthorization {
+        network_id: env.ledger().network_id().to_array().into(),
+        nonce,
+        signature_expiration_ledger,
+        invocation: invocation.clone(),
+    });
+    let preimage = Bytes::from_slice(env, &preimage.to_xdr(Limits::none()).unwrap());
+    let payload = env.crypto().sha256(&preimage).to_bytes();
+    let signature = assertion(
+        env,
+        signing_key,
+        authenticator_data(env, FLAG_USER_PRESENT),
+        client_data_json(env, "webauthn.get", &payload),
+    );
+
+    SorobanAuthorizationEntry {
+        credentials: SorobanCredentials::Address(SorobanAddressCredentials {
+            address: account.into(),
+            nonce,
+            signature_expiration_ledger,
+            signature: ScVal::try_from_val(env, &signature).unwrap(),
+        }),
+        root_invocation: invocation,
+    }
+}
+
+#[test]
+fn test_passkey_authorizes_withdrawal_and_admin_actions() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (account, client) = setup_account(&env);
+
+    let signing_key = passkey();
+    client.add_passkey(
+        &public_key(&env, &signing_key),
+        &rp_id_hash(&env),
+        &account_permissions(&env, &account),
+    );
+
+    let contexts = vec![
+        &env,
+        call(&env, &account, "withdraw"),
+        call(&env, &account, "set_payout_allowlist"),
+    ];
+    assert_eq!(
+        check_auth(&env, &account, signed(&env, &signing_key), contexts),
+        Ok(())
+    );
+
+    let remove_passkey = call(&env, &account, "remove_passkey");
+    assert_eq!(
+        check_auth(
+            &env,
+            &account,
+            signed(&env, &signing_key),
+            vec![&env, remove_passkey]
+        ),
+        Err(Ok(ContractError::CallNotPermitted))
+    );
+}
+
+#[test]
+fn test_passkey_transfer_limited_by_spend_limit() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (account, client) = setup_account(&env);
+    let token = Address::generate(&env);
+
+    let signing_key = passkey();
+    let key = public_key(&env, &signing_key);
+    client.add_passkey(&key, &rp_id_hash(&env), &spend_permissions(&env, &token));
+
+    let transfer = transfer(&env, &account, &token, &Address::generate(&env), 400);
+    assert_eq!(
+        check_auth(
+            &env,
+            &account,
+            signed(&env, &signing_key),
+            vec![&env, transfer.clone()]
+        ),
+        Ok(())
+    );
+    assert_eq!(client.get_passkey_spent(&key, &token), 400);
+    assert_eq!(
+        check_auth(
+            &env,
+            &account,
+            signed(&env, &signing_key),
+            vec![&env, transfer]
+        ),
+        Err(Ok(ContractError::SpendLimitExceeded))
+    );
+}
+
+#[test]
+fn test_assertion_for_other_challenge_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (account, client) = setup_account(&env);
+
+    let signing_key = passkey();
+    client.add_passkey(
+        &public_key(&env, &signing_key),
+        &rp_id_hash(&env),
+        &account_permissions(&env, &account),
+    );
+
+    let other_payload = BytesN::from_array(&env, &[2u8; 32]);
+    let signature = assertion(
+        &env,
+        &signing_key,
+        authenticator_data(&env, FLAG_USER_PRESENT),
+        client_data_json(&env, "webauthn.get", &other_payload),
+    );
+    assert_eq!(
+        check_auth(
+            &env,
+            &account,
+            signature,
+            vec![&env, call(&env, &account, "withdraw")]
+        ),
+        Err(Ok(ContractError::InvalidAssertion))
+    );
+}
+
+#[test]
+fn test_assertion_without_user_presence_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (account, client) = setup_account(&env);
+
+    let signing_key = passkey();
+    client.add_passkey(
+        &public_key(&env, &signing_key),
+        &rp_id_hash(&env),
+        &account_permissions(&env, &account),
+    );
+
+    let payload = BytesN::from_array(&env, &[1u8; 32]);
+    let signature = assertion(
+        &env,
+        &signing_key,
+        authenticator_data(&env, 0),
+        client_data_json(&env, "webauthn.get", &payload),
+    );
+    assert_eq!(
+        check_auth(
+            &env,
+            &account,
+            signature,
+            vec![&env, call(&env, &account, "withdraw")]
+        ),
+        Err(Ok(ContractError::InvalidAssertion))
+    );
+
+    let registration = assertion(
+        &env,
+        &signing_key,
+        authenticator_data(&env, FLAG_USER_PRESENT),
+        client_data_json(&env, "webauthn.create", &payload),
+    );
+    assert_eq!(
+        check_auth(
+            &env,
+            &account,
+            registration,
+            vec![&env, call(&env, &account, "withdraw")]
+        ),
+        Err(Ok(ContractError::InvalidAssertion))
+    );
+}
+
+#[test]
+fn test_unknown_or_removed_passkey_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (account, client) = setup_account(&env);
+
+    let signing_key = passkey();
+    let withdraw = call(&env, &account, "withdraw");
+    assert_eq!(
+        check_auth(
+            &env,
+            &account,
+            signed(&env, &signing_key),
+            vec![&env, withdraw.clone()]
+        ),
+        Err(Ok(ContractError::SignerNotFound))
+    );
+
+    let key = public_key(&env, &signing_key);
+    client.add_passkey(
+        &key,
+        &rp_id_hash(&env),
+        &account_permissions(&env, &account),
+    );
+    let passkey = client.get_passkey(&key);
+    assert_eq!(passkey.rp_id_hash, rp_id_hash(&env));
+    assert_eq!(passkey.permissions.allowed_calls.len(), 2);
+    client.remove_passkey(&key);
+
+    assert_eq!(
+        check_auth(
+            &env,
+            &account,
+            signed(&env, &signing_key),
+            vec![&env, withdraw]
+        ),
+        Err(Ok(ContractError::SignerNotFound))
+    );
+}
+
+#[test]
+fn test_tampered_authenticator_data_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (account, client) = setup_account(&env);
+
+    let signing_key = passkey();
+    client.add_passkey(
+        &public_key(&env, &signing_key),
+        &rp_id_hash(&env),
+        &account_permissions(&env, &account),
+    );
+
+    let payload = BytesN::from_array(&env, &[1u8; 32]);
+    let signature = assertion(
+        &env,
+        &signing_key,
+        authenticator_data(&env, FLAG_USER_PRESENT),
+        client_data_json(&env, "webauthn.get", &payload),
+    );
+    let mut signature = AccountSignature::try_from_val(&env, &signature).unwrap();
+    let AccountSignature::Secp256r1(ref mut webauthn) = signature else {
+        unreachable!()
+    };
+    webauthn.authenticator_data = authenticator_data(&env, FLAG_USER_PRESENT | 0x04);
+
+    let result = check_auth(
+        &env,
+        &account,
+        signature.into_val(&env),
+        vec![&env, call(&env, &account, "withdraw")],
+    );
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_assertion_for_other_relying_party_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (account, client) = setup_account(&env);
+
+    let signing_key = passkey();
+    client.add_passkey(
+        &public_key(&env, &signing_key),
+        &BytesN::from_array(&env, &[8u8; 32]),
+        &account_permissions(&env, &account),
+    );
+
+    assert_eq!(
+        check_auth(
+            &env,
+            &account,
+            signed(&env, &signing_key),
+            vec![&env, call(&env, &account, "withdraw")]
+        ),
+        Err(Ok(ContractError::InvalidAssertion))
+    );
+}
+
+#[test]
+fn test_client_data_fields_must_lead() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (account, client) = setup_account(&env);
+
+    let signing_key = passkey();
+    client.add_passkey(
+        &public_key(&env, &signing_key),
+        &rp_id_hash(&env),
+        &account_permissions(&env, &account),
+    );
+
+    // The expected fields appear, but only inside another member.
+    let payload = BytesN::from_array(&env, &[1u8; 32]);
+    let mut json = Bytes::from_slice(&env, b"{\"type\":\"webauthn.create\",\"x\":");
+    json.append(&client_data_json(&env, "webauthn.get", &payload));
+    json.extend_from_slice(b"}");
+    let signature = assertion(
+        &env,
+        &signing_key,
+        authenticator_data(&env, FLAG_USER_PRESENT),
+        json,
+    );
+    assert_eq!(
+        check_auth(
+            &env,
+            &account,
+            signature,
+            vec![&env, call(&env, &account, "withdraw")]
+        ),
+        Err(Ok(ContractError::InvalidAssertion))
+    );
+}
+
+#[test]
+fn test_passkey_signs_real_token_transfer() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (account, client) = setup_account(&env);
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    token::StellarAssetClient::new(&env, &token).mint(&account, &1000);
+
+    let signing_key = passkey();
+    let key = public_key(&env, &signing_key);
+    client.add_passkey(&key, &rp_id_hash(&env), &spend_permissions(&env, &token));
+    let treasury = Address::generate(&env);
+    let token_client = token::Client::new(&env, &token);
+
+    env.set_auths(&[]);
+    assert!(token_client
+        .try_transfer(&account, &treasury, &200)
+        .is_err());
+
+    env.set_auths(&[authorize_transfer(
+        &env,
+        &account,
+        &signing_key,
+        &token,
+        &treasury,
+        200,
+        1,
+    )]);
+    token_client.transfer(&account, &treasury, &200);
+    assert_eq!(token_client.balance(&treasury), 200);
+    assert_eq!(client.get_passkey_spent(&key, &token), 200);
+    assert_eq!(client.get_withdrawn_in_window(&token), 200);
+
+    env.set_auths(&[authorize_transfer(
+        &env,
+        &account,
+        &signing_key,
+        &token,
+        &treasury,
+        400,
+        2,
+    )]);
+    assert!(token_client
+        .try_transfer(&account, &treasury, &400)
+        .is_err());
+    assert_eq!(token_client.balance(&treasury), 200);
+}
+
+#[test]
+fn test_passkey_spend_requires_verified_account() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (account, client) = setup_account(&env);
+    let token = Address::generate(&env);
+    client.set_verified(&false);
+
+    let signing_key = passkey();
+    client.add_passkey(
+        &public_key(&env, &signing_key),
+        &rp_id_hash(&env),
+        &spend_permissions(&env, &token),
+    );
+
+    let spend = transfer(&env, &account, &token, &Address::generate(&env), 100);
+    assert_eq!(
+        check_auth(
+            &env,
+            &account,
+            signed(&env, &signing_key),
+            vec![&env, spend]
+        ),
+        Err(Ok(ContractError::AccountNotVerified))
+    );
+}
+
+#[test]
+fn test_passkey_spend_respects_payout_allowlist() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (account, client) = setup_account(&env);
+    let token = Address::generate(&env);
+    let payout = Address::generate(&env);
+    client.set_payout_allowlist(&vec![&env, payout.clone()]);
+
+    let signing_key = passkey();
+    client.add_passkey(
+        &public_key(&env, &signing_key),
+        &rp_id_hash(&env),
+        &spend_permissions(&env, &token),
+    );
+
+    let elsewhere = transfer(&env, &account, &token, &Address::generate(&env), 100);
+    assert_eq!(
+        check_auth(
+            &env,
+            &account,
+            signed(&env, &signing_key),
+            vec![&env, elsewhere]
+        ),
+        Err(Ok(ContractError::PayoutAddressNotAllowed))
+    );
+
+    let allowed = transfer(&env, &account, &token, &payout, 100);
+    assert_eq!(
+        check_auth(
+            &env,
+            &account,
+            signed(&env, &signing_key),
+            vec![&env, allowed]
+        ),
+        Ok(())
+    );
+}
+
+#[test]
+fn test_passkey_spend_counts_against_withdrawal_limit() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (account, client) = setup_account(&env);
+    let token = Address::generate(&env);
+    client.set_withdrawal_limit(&token, &Some(400));
+
+    let signing_key = passkey();
+    client.add_passkey(
+        &public_key(&env, &signing_key),
+        &rp_id_hash(&env),
+        &spend_permissions(&env, &token),
+    );
+
+    let first = transfer(&env, &account, &token, &Address::generate(&env), 300);
+    assert_eq!(
+        check_auth(
+            &env,
+            &account,
+            signed(&env, &signing_key),
+            vec![&env, first]
+        ),
+        Ok(())
+    );
+
+    let second = transfer(&env, &account, &token, &Address::generate(&env), 150);
+    assert_eq!(
+        check_auth(
+            &env,
+            &account,
+            signed(&env, &signing_key),
+            vec![&env, second]
+        ),
+        Err(Ok(ContractError::WithdrawalLimitExceeded))
+    );
+}
+
+#[test]
+fn test_passkey_spend_blocked_by_timelock_and_approval_threshold() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (account, client) = setup_account(&env);
+    let token = Address::generate(&env);
+
+    let signing_key = passkey();
+    client.add_passkey(
+        &public_key(&env, &signing_key),
+        &rp_id_hash(&env),
+        &spend_permissions(&env, &token),
+    );
+
+    client.set_approval_threshold(&Vec::new(&env), &token, &Some(100));
+    let large = transfer(&env, &account, &token, &Address::generate(&env), 200);
+    assert_eq!(
+        check_auth(
+            &env,
+            &account,
+            signed(&env, &signing_key),
+            vec![&env, large]
+        ),
+        Err(Ok(ContractError::ApprovalRequired))
+    );
+
+    client.set_withdrawal_delay(&(24 * 60 * 60));
+    let small = transfer(&env, &account, &token, &Address::generate(&env), 50);
+    assert_eq!(
+        check_auth(
+            &env,
+            &account,
+            signed(&env, &signing_key),
+            vec![&env, small]
+        ),
+        Err(Ok(ContractError::TimelockRequired))
+    );
+}
@@ -0,0 +1,196 @@
+#![cfg(test)]
+
+use crate::account::{MerchantAccount, MerchantAccountClient};
+use crate::types::WithdrawalStatus;
+use soroban_sdk::testutils::{Address as _, Events as _, Ledger};
+use soroban_sdk::{token, vec, Address, Env, Symbol, TryFromVal, Vec};
+
+const DELAY: u64 = 48 * 60 * 60;
+
+fn setup_timelocked_account(env: &Env) -> (MerchantAccountClient<'_>, Address, Address) {
+    let contract_id = env.register(MerchantAccount, ());
+    let client = MerchantAccountClient::new(env, &contract_id);
+
+    let merchant = Address::generate(env);
+    client.initialize(&merchant, &Address::generate(env), &1);
+    client.verify_account();
+    client.set_withdrawal_delay(&DELAY);
+
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    token::StellarAssetClient::new(env, &token).mint(&contract_id, &1000);
+
+    (client, merchant, token)
+}
+
+fn last_event_name(env: &Env) -> Symbol {
+    let events = env.events().all();
+    let (_contract_id, topics, _data) = events.get(events.len() - 1).unwrap();
+    Symbol::try_from_val(env, &topics.get(0).unwrap()).unwrap()
+}
+
+#[test]
+fn test_queued_withdrawal_executes_after_delay() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, merchant, token) = setup_timelocked_account(&env);
+
+    let withdrawal_id = client.queue_withdrawal(&token, &400, &merchant);
+    assert_eq!(
+        last_event_name(&env),
+        Symbol::new(&env, "withdrawal_queued_event")
+    );
+
+    let pending = client.get_pending_withdrawals();
+    assert_eq!(pending.len(), 1);
+    assert_eq!(pending.get(0).unwrap().unlocks_at, DELAY);
+    assert!(client
+        .try_execute_queued_withdrawal(&withdrawal_id)
+        .is_err());
+
+    env.ledger().with_mut(|li| li.timestamp += DELAY);
+    client.execute_queued_withdrawal(&withdrawal_id);
+    assert_eq!(
+        last_event_name(&env),
+        Symbol::new(&env, "timelock_executed_event")
+    );
+
+    assert_eq!(token::Client::new(&env, &token).balance(&merchant), 400);
+    assert_eq!(
+        client.get_queued_withdrawal(&withdrawal_id).status,
+        WithdrawalStatus::Executed
+    );
+    assert!(client.get_pending_withdrawals().is_empty());
+}
+
+#[test]
+fn test_manager_vetoes_within_window() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, merchant, token) = setup_timelocked_account(&env);
+
+    let withdrawal_id = client.queue_withdrawal(&token, &400, &merchant);
+    client.veto_withdrawal(&withdrawal_id);
+    assert_eq!(
+        last_event_name(&env),
+        Symbol::new(&env, "withdrawal_vetoed_event")
+    );
+
+    assert_eq!(
+        client.get_queued_withdrawal(&withdrawal_id).status,
+        WithdrawalStatus::Vetoed
+    );
+    assert!(client.get_pending_withdrawals().is_empty());
+
+    env.ledger().with_mut(|li| li.timestamp += DELAY);
+    assert!(client
+        .try_execute_queued_withdrawal(&withdrawal_id)
+        .is_err());
+    assert_eq!(client.get_balance(&token), 1000);
+}
+
+#[should_panic(expected = "HostError: Error(Contract, #20)")]
+#[test]
+fn test_veto_after_window_closes() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, merchant, token) = setup_timelocked_account(&env);
+
+    let withdrawal_id = client.queue_withdrawal(&token, &400, &merchant);
+    env.ledger().with_mut(|li| li.timestamp += DELAY);
+    client.veto_withdrawal(&withdrawal_id);
+}
+
+#[test]
+fn test_merchant_cancels_queued_withdrawal() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, merchant, token) = setup_timelocked_account(&env);
+
+    let first = client.queue_withdrawal(&token, &100, &merchant);
+    let second = client.queue_withdrawal(&token, &200, &merchant);
+    client.cancel_queued_withdrawal(&first);
+
+    let pending = client.get_pending_withdrawals();
+    assert_eq!(pending.len(), 1);
+    assert_eq!(pending.get(0).unwrap().id, second);
+    assert_eq!(
+        client.get_queued_withdrawal(&first).status,
+        WithdrawalStatus::Cancelled
+    );
+}
+
+#[test]
+fn test_approved_proposal_goes_through_timelock() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, merchant, token) = setup_timelocked_account(&env);
+    client.set_signers(&Vec::new(&env), &vec![&env, merchant.clone()], &1);
+    client.set_approval_threshold(&vec![&env, merchant.clone()], &token, &Some(500));
+
+    let proposal_id = client.propose_withdrawal(&merchant, &token, &900, &merchant);
+    client.execute_withdrawal(&merchant, &proposal_id);
+    assert_eq!(client.get_balance(&token), 1000);
+
+    let withdrawal_id = client
+        .get_proposal(&proposal_id)
+        .queued_withdrawal_id
+        .unwrap();
+    let withdrawal = client.get_queued_withdrawal(&withdrawal_id);
+    assert_eq!(withdrawal.amount, 900);
+    assert_eq!(withdrawal.status, WithdrawalStatus::Pending);
+    assert!(client
+        .try_execute_queued_withdrawal(&withdrawal_id)
+        .is_err());
+
+    client.veto_withdrawal(&withdrawal_id);
+    env.ledger().with_mut(|li| li.timestamp += DELAY);
+    assert!(client
+        .try_execute_queued_withdrawal(&withdrawal_id)
+        .is_err());
+    assert_eq!(client.get_balance(&token), 1000);
+}
+
+#[test]
+fn test_approved_proposal_executes_after_delay() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, merchant, token) = setup_timelocked_account(&env);
+    client.set_signers(&Vec::new(&env), &vec![&env, merchant.clone()], &1);
+    client.set_approval_threshold(&vec![&env, merchant.clone()], &token, &Some(500));
+
+    let proposal_id = client.propose_withdrawal(&merchant, &token, &900, &merchant);
+    client.execute_withdrawal(&merchant, &proposal_id);
+    let withdrawal_id = client
+        .get_proposal(&proposal_id)
+        .queued_withdrawal_id
+        .unwrap();
+
+    env.ledger().with_mut(|li| li.timestamp += DELAY);
+    client.execute_queued_withdrawal(&withdrawal_id);
+    assert_eq!(token::Client::new(&env, &token).balance(&merchant), 900);
+}
+
+#[should_panic(expected = "HostError: Error(Contract, #16)")]
+#[test]
+fn test_direct_withdraw_blocked_by_timelock() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, merchant, token) = setup_timelocked_account(&env);
+
+    client.withdraw(&token, &100, &merchant);
+}
+
+#[should_panic(expected = "HostError: Error(Contract, #18)")]
+#[test]
+fn test_cancel_executed_withdrawal() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, merchant, token) = setup_timelocked_account(&env);
+
+    let withdrawal_id = client.queue_withdrawal(&token, &100, &merchant);
+    env.ledger().with_mut(|li| li.timestamp += DELAY);
+    client.execute_queued_withdrawal(&withdrawal_id);
+    client.cancel_queued_withdrawal(&withdrawal_id);
+}
@@ -0,0 +1,185 @@
+#![cfg(test)]
+
+use crate::account::{MerchantAccount, MerchantAccountClient, WITHDRAWAL_WINDOW};
+use soroban_sdk::testutils::{Address as _, Events as _, Ledger};
+use soroban_sdk::{token, vec, Address, Env, Map, Symbol, TryFromVal, Val, Vec};
+
+fn setup_funded_account(env: &Env) -> (Address, MerchantAccountClient<'_>, Address, Address) {
+    let contract_id = env.register(MerchantAccount, ());
+    let client = MerchantAccountClient::new(env, &contract_id);
+
+    let merchant = Address::generate(env);
+    let manager = Address::generate(env);
+    client.initialize(&merchant, &manager, &1);
+    client.verify_account();
+
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    token::StellarAssetClient::new(env, &token).mint(&contract_id, &1000);
+
+    (contract_id, client, merchant, token)
+}
+
+#[test]
+fn test_withdraw_transfers_and_emits_event() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, merchant, token) = setup_funded_account(&env);
+
+    client.withdraw(&token, &400, &merchant);
+
+    let events = env.events().all();
+    let (event_contract_id, topics, data) = events.get(events.len() - 1).unwrap();
+    assert_eq!(event_contract_id, contract_id);
+    let event_name = Symbol::try_from_val(&env, &topics.get(0).unwrap()).unwrap();
+    assert_eq!(event_name, Symbol::new(&env, "withdrawal_event"));
+    let data_map = Map::<Symbol, Val>::try_from_val(&env, &data).unwrap();
+    let amount_in_event =
+        i128::try_from_val(&env, &data_map.get(Symbol::new(&env, "amount")).unwrap()).unwrap();
+    assert_eq!(amount_in_event, 400);
+
+    let token_client = token::Client::new(&env, &token);
+    assert_eq!(token_client.balance(&merchant), 400);
+    assert_eq!(client.get_balance(&token), 600);
+    assert_eq!(client.get_withdrawn_in_window(&token), 400);
+}
+
+#[test]
+fn test_withdrawal_limit_uses_rolling_window() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_contract_id, client, merchant, token) = setup_funded_account(&env);
+
+    client.set_withdrawal_limit(&token, &Some(500));
+    assert_eq!(client.get_withdrawal_limit(&token), Some(500));
+
+    client.withdraw(&token, &300, &merchant);
+    env.ledger()
+        .with_mut(|li| li.timestamp += WITHDRAWAL_WINDOW / 2);
+    client.withdraw(&token, &200, &merchant);
+    assert!(client.try_withdraw(&token, &1, &merchant).is_err());
+
+    env.ledger()
+        .with_mut(|li| li.timestamp += WITHDRAWAL_WINDOW / 2);
+    assert_eq!(client.get_withdrawn_in_window(&token), 200);
+    client.withdraw(&token, &300, &merchant);
+    assert_eq!(client.get_withdrawn_in_window(&token), 500);
+}
+
+#[should_panic(expected = "HostError: Error(Contract, #7)")]
+#[test]
+fn test_withdraw_over_limit() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_contract_id, client, merchant, token) = setup_funded_account(&env);
+
+    client.set_withdrawal_limit(&token, &Some(500));
+    client.withdraw(&token, &501, &merchant);
+}
+
+#[test]
+fn test_payout_allowlist() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_contract_id, client, _merchant, token) = setup_funded_account(&env);
+
+    let treasury = Address::generate(&env);
+    client.set_payout_allowlist(&vec![&env, treasury.clone()]);
+    assert_eq!(client.get_payout_allowlist(), vec![&env, treasury.clone()]);
+
+    client.withdraw(&token, &100, &treasury);
+    assert_eq!(token::Client::new(&env, &token).balance(&treasury), 100);
+
+    client.set_payout_allowlist(&Vec::new(&env));
+    client.withdraw(&token, &100, &Address::generate(&env));
+}
+
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+#[test]
+fn test_withdraw_to_address_outside_allowlist() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_contract_id, client, _merchant, token) = setup_funded_account(&env);
+
+    client.set_payout_allowlist(&vec![&env, Address::generate(&env)]);
+    client.withdraw(&token, &100, &Address::generate(&env));
+}
+
+#[should_panic(expected = "HostError: Error(Contract, #5)")]
+#[test]
+fn test_withdraw_from_unverified_account() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(MerchantAccount, ());
+    let client = MerchantAccountClient::new(&env, &contract_id);
+    let merchant = Address::generate(&env);
+    client.initialize(&merchant, &Address::generate(&env), &1);
+
+    client.withdraw(&Address::generate(&env), &100, &merchant);
+}
+
+#[should_panic(expected = "HostError: Error(Contract, #4)")]
+#[test]
+fn test_withdraw_more_than_balance() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_contract_id, client, merchant, token) = setup_funded_account(&env);
+
+    client.withdraw(&token, &1001, &merchant);
+}
+
+#[should_panic(expected = "HostError: Error(Contract, #3)")]
+#[test]
+fn test_withdraw_zero_amount() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_contract_id, client, merchant, token) = setup_funded_account(&env);
+
+    client.withdraw(&token, &0, &merchant);
+}
+
+#[test]
+fn test_withdraw_requires_merchant_auth() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_contract_id, client, merchant, token) = setup_funded_account(&env);
+
+    env.set_auths(&[]);
+    assert!(client.try_withdraw(&token, &100, &merchant).is_err());
+}
+
+#[test]
+fn test_manager_refund_bypasses_payout_policy() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_contract_id, client, merchant, token) = setup_funded_account(&env);
+    client.set_payout_allowlist(&vec![&env, merchant.clone()]);
+    client.set_withdrawal_limit(&token, &Some(100));
+
+    let customer = Address::generate(&env);
+    client.refund(&token, &customer, &400);
+
+    let events = env.events().all();
+    let (_contract_id, topics, _data) = events.get(events.len() - 1).unwrap();
+    let event_name = Symbol::try_from_val(&env, &topics.get(0).unwrap()).unwrap();
+    assert_eq!(event_name, Symbol::new(&env, "refund_event"));
+
+    assert_eq!(token::Client::new(&env, &token).balance(&customer), 400);
+    assert_eq!(client.get_withdrawn_in_window(&token), 0);
+}
+
+#[test]
+fn test_refund_requires_manager_auth() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_contract_id, client, merchant, token) = setup_funded_account(&env);
+
+    client.refund(&token, &Address::generate(&env), &100);
+    assert_ne!(env.auths().first().unwrap().0, merchant);
+
+    env.set_auths(&[]);
+    assert!(client
+        .try_refund(&token, &Address::generate(&env), &100)
+        .is_err());
+}
@@ -1,4 +1,4 @@
-use soroban_sdk::{contracttype, Address};
+use soroban_sdk::{contracttype, Address, Bytes, BytesN, Symbol, Vec};
 
 #[contracttype]
 pub enum DataKey {
@@ -7,6 +7,22 @@ pub enum DataKey {
     Verified,
     AccountInfo,
     TrackedTokens,
+    PayoutAllowlist,
+    WithdrawalLimit(Address),
+    Withdrawals(Address),
+    Signers,
+    SignerThreshold,
+    ApprovalThreshold(Address),
+    UnapprovedWithdrawals(Address),
+    Proposal(u64),
+    ProposalCount,
+    WithdrawalDelay,
+    QueuedWithdrawal(u64),
+    QueuedWithdrawalCount,
+    PendingWithdrawals,
+    SignerKey(BytesN<32>),
+    SignerSpend(BytesN<32>, Address),
+    Passkey(BytesN<65>),
 }
 
 #[contracttype]
@@ -24,3 +40,107 @@ pub struct TokenBalance {
     pub token: Address,
     pub balance: i128,
 }
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WithdrawalRecord {
+    pub amount: i128,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WithdrawalProposal {
+    pub id: u64,
+    pub proposer: Address,
+    pub token: Address,
+    pub amount: i128,
+    pub to: Address,
+    pub approvals: Vec<Address>,
+    pub status: ProposalStatus,
+    pub created_at: u64,
+    pub expires_at: u64,
+    pub queued_withdrawal_id: Option<u64>,
+}
+
+#[contracttype]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum ProposalStatus {
+    Pending = 0,
+    Executed = 1,
+    Cancelled = 2,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct QueuedWithdrawal {
+    pub id: u64,
+    pub token: Address,
+    pub amount: i128,
+    pub to: Address,
+    pub status: WithdrawalStatus,
+    pub queued_at: u64,
+    pub unlocks_at: u64,
+}
+
+#[contracttype]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum WithdrawalStatus {
+    Pending = 0,
+    Executed = 1,
+    Vetoed = 2,
+    Cancelled = 3,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CallPermission {
+    pub contract: Address,
+    pub function: Option<Symbol>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SpendLimit {
+    pub token: Address,
+    pub limit: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SignerPermissions {
+    pub allowed_calls: Vec<CallPermission>,
+    pub spend_limits: Vec<SpendLimit>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PasskeySigner {
+    pub rp_id_hash: BytesN<32>,
+    pub permissions: SignerPermissions,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Ed25519Signature {
+    pub public_key: BytesN<32>,
+    pub signature: BytesN<64>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WebAuthnSignature {
+    pub public_key: BytesN<65>,
+    pub authenticator_data: Bytes,
+    pub client_data_json: Bytes,
+    pub signature: BytesN<64>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AccountSignature {
+    Ed25519(Ed25519Signature),
+    Secp256r1(WebAuthnSignature),
+}
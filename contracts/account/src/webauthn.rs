@@ -0,0 +1,83 @@
+use crate::errors::ContractError;
+use crate::types::WebAuthnSignature;
+use soroban_sdk::crypto::Hash;
+use soroban_sdk::{Bytes, BytesN, Env};
+
+pub const MAX_CLIENT_DATA_LEN: u32 = 1024;
+const RP_ID_HASH_LEN: u32 = 32;
+const AUTH_DATA_FLAGS_INDEX: u32 = 32;
+const FLAG_USER_PRESENT: u8 = 0x01;
+const CHALLENGE_LEN: usize = 43;
+// Browsers serialize `type` and `challenge` first, in this order.
+const CLIENT_DATA_PREFIX: &[u8] = b"{\"type\":\"webauthn.get\",\"challenge\":\"";
+const CLIENT_DATA_HEADER_LEN: usize = CLIENT_DATA_PREFIX.len() + CHALLENGE_LEN + 1;
+const BASE64_URL_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+pub fn verify(
+    env: &Env,
+    signature_payload: &Hash<32>,
+    signature: &WebAuthnSignature,
+    rp_id_hash: &BytesN<32>,
+) -> Result<(), ContractError> {
+    let authenticator_data = &signature.authenticator_data;
+    if authenticator_data.len() <= AUTH_DATA_FLAGS_INDEX
+        || authenticator_data.slice(..RP_ID_HASH_LEN) != Bytes::from(rp_id_hash)
+    {
+        return Err(ContractError::InvalidAssertion);
+    }
+    let flags = authenticator_data
+        .get(AUTH_DATA_FLAGS_INDEX)
+        .ok_or(ContractError::InvalidAssertion)?;
+    if flags & FLAG_USER_PRESENT == 0 {
+        return Err(ContractError::InvalidAssertion);
+    }
+
+    let client_data_len = signature.client_data_json.len();
+    if client_data_len > MAX_CLIENT_DATA_LEN || client_data_len < CLIENT_DATA_HEADER_LEN as u32 {
+        return Err(ContractError::InvalidAssertion);
+    }
+
+    let mut expected = [0u8; CLIENT_DATA_HEADER_LEN];
+    expected[..CLIENT_DATA_PREFIX.len()].copy_from_slice(CLIENT_DATA_PREFIX);
+    encode_challenge(
+        &signature_payload.to_array(),
+        &mut expected[CLIENT_DATA_PREFIX.len()..CLIENT_DATA_PREFIX.len() + CHALLENGE_LEN],
+    );
+    expected[CLIENT_DATA_HEADER_LEN - 1] = b'"';
+
+    let mut header = [0u8; CLIENT_DATA_HEADER_LEN];
+    signature
+        .client_data_json
+        .slice(..CLIENT_DATA_HEADER_LEN as u32)
+        .copy_into_slice(&mut header);
+    if header != expected {
+        return Err(ContractError::InvalidAssertion);
+    }
+
+    let mut message = Bytes::new(env);
+    message.append(&signature.authenticator_data);
+    message.extend_from_array(&env.crypto().sha256(&signature.client_data_json).to_array());
+    let digest = env.crypto().sha256(&message);
+    env.crypto()
+        .secp256r1_verify(&signature.public_key, &digest, &signature.signature);
+    Ok(())
+}
+
+fn encode_challenge(payload: &[u8; 32], out: &mut [u8]) {
+    let mut bits: u32 = 0;
+    let mut bit_count = 0;
+    let mut index = 0;
+    for byte in payload.iter() {
+        bits = (bits << 8) | *byte as u32;
+        bit_count += 8;
+        while bit_count >= 6 {
+            bit_count -= 6;
+            out[index] = BASE64_URL_ALPHABET[((bits >> bit_count) & 0x3f) as usize];
+            index += 1;
+        }
+    }
+    if bit_count > 0 {
+        out[index] = BASE64_URL_ALPHABET[((bits << (6 - bit_count)) & 0x3f) as usize];
+    }
+}
@@ -1,7 +1,8 @@
 use crate::components::{core, reentrancy};
+use crate::errors::ContractError;
 use crate::events;
-use crate::types::DataKey;
-use soroban_sdk::{token, Address, Env, Vec};
+use crate::types::{DataKey, TokenLimits};
+use soroban_sdk::{panic_with_error, token, Address, Env, Vec};
 
 pub fn add_accepted_token(env: &Env, admin: &Address, token: &Address) {
     reentrancy::enter(env);
@@ -49,6 +50,73 @@ pub fn is_accepted_token(env: &Env, token: &Address) -> bool {
     contains_token(&get_accepted_tokens(env), token)
 }
 
+pub fn assert_accepted_token(env: &Env, token: &Address) {
+    if !is_accepted_token(env, token) {
+        panic_with_error!(env, ContractError::TokenNotAccepted);
+    }
+}
+
+pub fn set_token_limits(
+    env: &Env,
+    admin: &Address,
+    token: &Address,
+    min_amount: Option<i128>,
+    max_amount: Option<i128>,
+) {
+    core::assert_admin(env, admin);
+
+    if let Some(min_amount) = min_amount {
+        if min_amount <= 0 {
+            panic_with_error!(env, ContractError::InvalidAmount);
+        }
+    }
+    if let Some(max_amount) = max_amount {
+        if max_amount <= 0 || max_amount < min_amount.unwrap_or(0) {
+            panic_with_error!(env, ContractError::InvalidAmount);
+        }
+    }
+
+    let limits = TokenLimits {
+        min_amount,
+        max_amount,
+    };
+    env.storage()
+        .persistent()
+        .set(&DataKey::TokenLimits(token.clone()), &limits);
+
+    events::publish_token_limits_set_event(
+        env,
+        token.clone(),
+        min_amount,
+        max_amount,
+        env.ledger().timestamp(),
+    );
+}
+
+pub fn get_token_limits(env: &Env, token: &Address) -> TokenLimits {
+    env.storage()
+        .persistent()
+        .get(&DataKey::TokenLimits(token.clone()))
+        .unwrap_or(TokenLimits {
+            min_amount: None,
+            max_amount: None,
+        })
+}
+
+pub fn assert_within_token_limits(env: &Env, token: &Address, amount: i128) {
+    let limits = get_token_limits(env, token);
+    if let Some(min_amount) = limits.min_amount {
+        if amount < min_amount {
+            panic_with_error!(env, ContractError::AmountBelowMinimum);
+        }
+    }
+    if let Some(max_amount) = limits.max_amount {
+        if amount > max_amount {
+            panic_with_error!(env, ContractError::AmountAboveMaximum);
+        }
+    }
+}
+
 fn get_accepted_tokens(env: &Env) -> Vec<Address> {
     env.storage()
         .persistent()
@@ -0,0 +1,207 @@
+use crate::components::{
+    access_control, core, escrow, fee, invoice, merchant, merchant_account, reentrancy, split,
+};
+use crate::errors::ContractError;
+use crate::events;
+use crate::types::{DataKey, Dispute, Invoice, InvoiceStatus, Merchant, Role};
+use soroban_sdk::{panic_with_error, token, Address, BytesN, Env};
+
+pub const MAX_SHARE_BASIS_POINTS: u32 = 10_000;
+pub const DEFAULT_DISPUTE_WINDOW: u64 = 7 * 24 * 60 * 60;
+
+pub fn set_dispute_window(env: &Env, admin: &Address, window: u64) {
+    core::assert_admin(env, admin);
+
+    env.storage()
+        .persistent()
+        .set(&DataKey::DisputeWindow, &window);
+
+    events::publish_dispute_window_set_event(env, window, env.ledger().timestamp());
+}
+
+pub fn get_dispute_window(env: &Env) -> u64 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::DisputeWindow)
+        .unwrap_or(DEFAULT_DISPUTE_WINDOW)
+}
+
+pub fn open_dispute(env: &Env, payer: &Address, invoice_id: u64, evidence_hash: &BytesN<32>) {
+    reentrancy::enter(env);
+    payer.require_auth();
+
+    let mut invoice = invoice::get_invoice(env, invoice_id);
+    if invoice.payer != Some(payer.clone()) {
+        panic_with_error!(env, ContractError::NotAuthorized);
+    }
+
+    if env
+        .storage()
+        .persistent()
+        .has(&DataKey::Dispute(invoice_id))
+    {
+        panic_with_error!(env, ContractError::InvoiceNotDisputable);
+    }
+
+    let amount = match invoice.status {
+        InvoiceStatus::Escrowed => {
+            if env.ledger().timestamp() >= invoice.escrow_release_at.unwrap() {
+                panic_with_error!(env, ContractError::DisputeWindowClosed);
+            }
+            escrow::held_amount(&invoice)
+        }
+        InvoiceStatus::Paid => freeze_paid_funds(env, &invoice),
+        _ => panic_with_error!(env, ContractError::InvoiceNotDisputable),
+    };
+
+    invoice.status = InvoiceStatus::Disputed;
+    env.storage()
+        .persistent()
+        .set(&DataKey::Invoice(invoice_id), &invoice);
+
+    let dispute = Dispute {
+        invoice_id,
+        payer: payer.clone(),
+        evidence_hash: evidence_hash.clone(),
+        amount,
+        opened_at: env.ledger().timestamp(),
+        resolved: false,
+        resolver: None,
+        payer_amount: 0,
+        merchant_amount: 0,
+        resolved_at: None,
+    };
+    env.storage()
+        .persistent()
+        .set(&DataKey::Dispute(invoice_id), &dispute);
+
+    events::publish_dispute_opened_event(
+        env,
+        invoice_id,
+        payer.clone(),
+        evidence_hash.clone(),
+        env.ledger().timestamp(),
+    );
+    reentrancy::exit(env);
+}
+
+// A settled invoice can only be disputed when its merchant has an account
+// Shade manages. The merchant's share is pulled back from the account into
+// custody until the dispute is resolved.
+fn freeze_paid_funds(env: &Env, invoice: &Invoice) -> i128 {
+    let paid_at = invoice.date_paid.unwrap_or(invoice.date_created);
+    if env.ledger().timestamp() >= paid_at + get_dispute_window(env) {
+        panic_with_error!(env, ContractError::DisputeWindowClosed);
+    }
+
+    let merchant = merchant::get_merchant(env, invoice.merchant_id);
+    let account = merchant
+        .account
+        .clone()
+        .unwrap_or_else(|| panic_with_error!(env, ContractError::InvoiceNotDisputable));
+    let amount = invoice::merchant_share(invoice, &merchant) - invoice.amount_refunded;
+    if amount <= 0 {
+        panic_with_error!(env, ContractError::InvoiceNotDisputable);
+    }
+
+    merchant_account::MerchantAccountClient::new(env, &account).refund(
+        &invoice.token,
+        &env.current_contract_address(),
+        &amount,
+    );
+    amount
+}
+
+pub fn resolve_dispute(
+    env: &Env,
+    operator: &Address,
+    invoice_id: u64,
+    payer_share_basis_points: u32,
+) {
+    reentrancy::enter(env);
+    access_control::assert_has_role(env, operator, Role::Operator);
+
+    if payer_share_basis_points > MAX_SHARE_BASIS_POINTS {
+        panic_with_error!(env, ContractError::InvalidSplit);
+    }
+
+    let mut dispute = get_dispute(env, invoice_id);
+    let mut invoice = invoice::get_invoice(env, invoice_id);
+    if dispute.resolved || invoice.status != InvoiceStatus::Disputed {
+        panic_with_error!(env, ContractError::InvoiceNotDisputable);
+    }
+
+    let merchant = merchant::get_merchant(env, invoice.merchant_id);
+    let amount = dispute.amount;
+    let payer_amount = amount * payer_share_basis_points as i128 / MAX_SHARE_BASIS_POINTS as i128;
+    let merchant_amount = amount - payer_amount;
+
+    invoice.amount_refunded += payer_amount;
+    invoice.status = if invoice.amount_refunded == invoice.amount_paid {
+        InvoiceStatus::Refunded
+    } else if invoice.escrow {
+        InvoiceStatus::Released
+    } else {
+        InvoiceStatus::Paid
+    };
+    dispute.resolved = true;
+    dispute.resolver = Some(operator.clone());
+    dispute.payer_amount = payer_amount;
+    dispute.merchant_amount = merchant_amount;
+    dispute.resolved_at = Some(env.ledger().timestamp());
+    env.storage()
+        .persistent()
+        .set(&DataKey::Dispute(invoice_id), &dispute);
+
+    let token_client = token::Client::new(env, &invoice.token);
+    let contract_address = env.current_contract_address();
+    if payer_amount > 0 {
+        token_client.transfer(&contract_address, &dispute.payer, &payer_amount);
+    }
+    if invoice.escrow {
+        let fee_amount = fee::calculate_fee(env, &invoice.token, merchant_amount);
+        split::settle(
+            env,
+            &mut invoice,
+            &contract_address,
+            &merchant,
+            merchant_amount - fee_amount,
+        );
+        fee::collect_fee(env, &invoice.token, fee_amount);
+    } else {
+        return_frozen_funds(env, &merchant, &invoice, merchant_amount);
+    }
+    env.storage()
+        .persistent()
+        .set(&DataKey::Invoice(invoice_id), &invoice);
+
+    events::publish_dispute_resolved_event(
+        env,
+        invoice_id,
+        operator.clone(),
+        payer_amount,
+        merchant_amount,
+        env.ledger().timestamp(),
+    );
+    reentrancy::exit(env);
+}
+
+// Fees and splits were already taken when the invoice was paid, so the
+// merchant's part of frozen funds goes straight back to its account.
+fn return_frozen_funds(env: &Env, merchant: &Merchant, invoice: &Invoice, amount: i128) {
+    if amount > 0 {
+        let account = merchant_account::settlement_address(env, merchant, &invoice.token);
+        token::Client::new(env, &invoice.token).transfer(
+            &env.current_contract_address(),
+            &account,
+            &amount,
+        );
+    }
+}
+
+pub fn get_dispute(env: &Env, invoice_id: u64) -> Dispute {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Dispute(invoice_id))
+        .unwrap_or_else(|| panic_with_error!(env, ContractError::DisputeNotFound))
+}
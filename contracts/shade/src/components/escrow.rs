@@ -0,0 +1,100 @@
+use crate::components::{core, fee, invoice, merchant, reentrancy, split};
+use crate::errors::ContractError;
+use crate::events;
+use crate::types::{DataKey, Invoice, InvoiceStatus};
+use soroban_sdk::{panic_with_error, Address, Env};
+
+pub const DEFAULT_ESCROW_RELEASE_WINDOW: u64 = 7 * 24 * 60 * 60;
+
+pub fn set_escrow_release_window(env: &Env, admin: &Address, window: u64) {
+    core::assert_admin(env, admin);
+
+    if window == 0 {
+        panic_with_error!(env, ContractError::InvalidEscrowWindow);
+    }
+
+    env.storage()
+        .persistent()
+        .set(&DataKey::EscrowReleaseWindow, &window);
+
+    events::publish_escrow_release_window_set_event(env, window, env.ledger().timestamp());
+}
+
+pub fn get_escrow_release_window(env: &Env) -> u64 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::EscrowReleaseWindow)
+        .unwrap_or(DEFAULT_ESCROW_RELEASE_WINDOW)
+}
+
+pub fn confirm_delivery(env: &Env, payer: &Address, invoice_id: u64) {
+    reentrancy::enter(env);
+    payer.require_auth();
+
+    let invoice = get_escrowed_invoice(env, invoice_id);
+    if invoice.payer != Some(payer.clone()) {
+        panic_with_error!(env, ContractError::NotAuthorized);
+    }
+
+    release(env, invoice);
+    reentrancy::exit(env);
+}
+
+pub fn release_escrow(env: &Env, invoice_id: u64) {
+    reentrancy::enter(env);
+
+    let invoice = get_escrowed_invoice(env, invoice_id);
+    let release_at = invoice.escrow_release_at.unwrap();
+    if env.ledger().timestamp() < release_at {
+        panic_with_error!(env, ContractError::EscrowReleaseNotDue);
+    }
+
+    release(env, invoice);
+    reentrancy::exit(env);
+}
+
+pub fn held_amount(invoice: &Invoice) -> i128 {
+    invoice.amount_paid - invoice.amount_refunded
+}
+
+pub fn is_in_custody(invoice: &Invoice) -> bool {
+    invoice.escrow && invoice.status != InvoiceStatus::Released
+}
+
+fn get_escrowed_invoice(env: &Env, invoice_id: u64) -> Invoice {
+    let invoice = invoice::get_invoice(env, invoice_id);
+    if invoice.status != InvoiceStatus::Escrowed {
+        panic_with_error!(env, ContractError::InvoiceNotEscrowed);
+    }
+    invoice
+}
+
+fn release(env: &Env, mut invoice: Invoice) {
+    let merchant = merchant::get_merchant(env, invoice.merchant_id);
+    let amount = held_amount(&invoice);
+
+    invoice.status = InvoiceStatus::Released;
+
+    let fee_amount = fee::calculate_fee(env, &invoice.token, amount);
+    let merchant_amount = amount - fee_amount;
+    split::settle(
+        env,
+        &mut invoice,
+        &env.current_contract_address(),
+        &merchant,
+        merchant_amount,
+    );
+    fee::collect_fee(env, &invoice.token, fee_amount);
+    env.storage()
+        .persistent()
+        .set(&DataKey::Invoice(invoice.id), &invoice);
+
+    events::publish_escrow_released_event(
+        env,
+        invoice.id,
+        merchant.address,
+        merchant_amount,
+        fee_amount,
+        env.ledger().timestamp(),
+    );
+}
@@ -0,0 +1,113 @@
+use crate::components::{core, reentrancy};
+use crate::errors::ContractError;
+use crate::events;
+use crate::types::{DataKey, TokenFee};
+use soroban_sdk::{panic_with_error, token, Address, Env};
+
+pub const MAX_BASIS_POINTS: u32 = 10_000;
+
+pub fn set_token_fee(
+    env: &Env,
+    admin: &Address,
+    token: &Address,
+    fee_in_basis_points: u32,
+    fee_amount: i128,
+) {
+    core::assert_admin(env, admin);
+
+    if fee_in_basis_points > MAX_BASIS_POINTS || fee_amount < 0 {
+        panic_with_error!(env, ContractError::InvalidFee);
+    }
+
+    env.storage().persistent().set(
+        &DataKey::FeeInBasisPoints(token.clone()),
+        &fee_in_basis_points,
+    );
+    env.storage()
+        .persistent()
+        .set(&DataKey::FeeAmount(token.clone()), &fee_amount);
+
+    events::publish_token_fee_set_event(
+        env,
+        token.clone(),
+        fee_in_basis_points,
+        fee_amount,
+        env.ledger().timestamp(),
+    );
+}
+
+pub fn get_token_fee(env: &Env, token: &Address) -> TokenFee {
+    let fee_in_basis_points: u32 = env
+        .storage()
+        .persistent()
+        .get(&DataKey::FeeInBasisPoints(token.clone()))
+        .unwrap_or(0);
+    let fee_amount: i128 = env
+        .storage()
+        .persistent()
+        .get(&DataKey::FeeAmount(token.clone()))
+        .unwrap_or(0);
+
+    TokenFee {
+        fee_in_basis_points,
+        fee_amount,
+    }
+}
+
+pub fn calculate_fee(env: &Env, token: &Address, amount: i128) -> i128 {
+    let fee = calculate_basis_points_fee(env, token, amount) + get_token_fee(env, token).fee_amount;
+
+    if fee > amount {
+        amount
+    } else {
+        fee
+    }
+}
+
+pub fn calculate_basis_points_fee(env: &Env, token: &Address, amount: i128) -> i128 {
+    let token_fee = get_token_fee(env, token);
+    amount * token_fee.fee_in_basis_points as i128 / MAX_BASIS_POINTS as i128
+}
+
+pub fn collect_fee(env: &Env, token: &Address, fee: i128) {
+    if fee <= 0 {
+        return;
+    }
+
+    let collected = get_collected_fees(env, token);
+    env.storage()
+        .persistent()
+        .set(&DataKey::CollectedFees(token.clone()), &(collected + fee));
+}
+
+pub fn get_collected_fees(env: &Env, token: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::CollectedFees(token.clone()))
+        .unwrap_or(0)
+}
+
+pub fn withdraw_fees(env: &Env, admin: &Address, token: &Address, treasury: &Address) {
+    reentrancy::enter(env);
+    core::assert_admin(env, admin);
+
+    let amount = get_collected_fees(env, token);
+    if amount <= 0 {
+        panic_with_error!(env, ContractError::InvalidAmount);
+    }
+
+    env.storage()
+        .persistent()
+        .remove(&DataKey::CollectedFees(token.clone()));
+
+    token::Client::new(env, token).transfer(&env.current_contract_address(), treasury, &amount);
+
+    events::publish_fees_withdrawn_event(
+        env,
+        token.clone(),
+        amount,
+        treasury.clone(),
+        env.ledger().timestamp(),
+    );
+    reentrancy::exit(env);
+}
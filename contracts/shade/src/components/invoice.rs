@@ -1,22 +1,61 @@
-use crate::components::merchant;
+use crate::components::{
+    access_control, admin, escrow, fee, merchant, merchant_account, oracle, reentrancy, split,
+};
 use crate::errors::ContractError;
 use crate::events;
-use crate::types::{DataKey, Invoice, InvoiceFilter, InvoiceStatus};
-use soroban_sdk::{panic_with_error, Address, Env, String, Vec};
+use crate::types::{
+    CancellationReason, DataKey, ExpiryUpdate, Invoice, InvoiceFilter, InvoicePayment, InvoiceSpec,
+    InvoiceStatus, LegacyInvoice, LineItem, Merchant, PaymentRequest, Role,
+};
+use soroban_sdk::xdr::ToXdr;
+use soroban_sdk::{
+    panic_with_error, symbol_short, token, Address, BytesN, Env, Map, String, Symbol, TryFromVal,
+    Val, Vec,
+};
 
-pub fn create_invoice(
+pub const MAX_BATCH_SIZE: u32 = 50;
+pub const MAX_ALLOWED_PAYERS: u32 = 10;
+
+pub fn create_invoice(env: &Env, merchant_address: &Address, spec: &InvoiceSpec) -> u64 {
+    merchant_address.require_auth();
+    insert_invoice(env, merchant_address, spec)
+}
+
+pub fn create_invoices(
     env: &Env,
     merchant_address: &Address,
-    description: &String,
-    amount: i128,
-    token: &Address,
-) -> u64 {
+    specs: &Vec<InvoiceSpec>,
+) -> Vec<u64> {
     merchant_address.require_auth();
+    assert_batch_size(env, specs.len());
 
-    if amount <= 0 {
+    let mut invoice_ids = Vec::new(env);
+    for spec in specs.iter() {
+        invoice_ids.push_back(insert_invoice(env, merchant_address, &spec));
+    }
+    invoice_ids
+}
+
+fn assert_batch_size(env: &Env, size: u32) {
+    if size == 0 || size > MAX_BATCH_SIZE {
+        panic_with_error!(env, ContractError::InvalidBatchSize);
+    }
+}
+
+fn insert_invoice(env: &Env, merchant_address: &Address, spec: &InvoiceSpec) -> u64 {
+    if spec.amount <= 0 {
         panic_with_error!(env, ContractError::InvalidAmount);
     }
 
+    admin::assert_accepted_token(env, &spec.token);
+    admin::assert_within_token_limits(env, &spec.token, spec.amount);
+
+    if let Some(expires_at) = spec.expires_at {
+        if expires_at <= env.ledger().timestamp() {
+            panic_with_error!(env, ContractError::InvalidExpiry);
+        }
+    }
+
     if !merchant::is_merchant(env, merchant_address) {
         panic_with_error!(env, ContractError::NotAuthorized);
     }
@@ -27,6 +66,175 @@ pub fn create_invoice(
         .get(&DataKey::MerchantId(merchant_address.clone()))
         .unwrap();
 
+    let new_invoice_id = next_invoice_id(env);
+
+    let mut invoice = new_invoice(
+        env,
+        new_invoice_id,
+        merchant_id,
+        &spec.description,
+        spec.amount,
+        &spec.token,
+    );
+    invoice.expires_at = spec.expires_at;
+    invoice.escrow = spec.escrow;
+    validate_allowed_payers(env, &spec.allowed_payers);
+    invoice.allowed_payers = spec.allowed_payers.clone();
+    if spec.splits.is_empty() {
+        invoice.splits = split::default_splits(env, merchant_id);
+    } else {
+        split::validate_splits(env, &spec.splits);
+        invoice.splits = spec.splits.clone();
+    }
+
+    if let Some(reference) = &spec.reference {
+        let reference_key = DataKey::InvoiceReference(merchant_id, reference.clone());
+        if env.storage().persistent().has(&reference_key) {
+            panic_with_error!(env, ContractError::DuplicateReference);
+        }
+        env.storage()
+            .persistent()
+            .set(&reference_key, &new_invoice_id);
+        invoice.reference = Some(reference.clone());
+    }
+
+    if !spec.line_items.is_empty() {
+        let (subtotal, discount_amount, tax_amount) = compute_line_totals(env, &spec.line_items);
+        if subtotal - discount_amount + tax_amount != spec.amount {
+            panic_with_error!(env, ContractError::InvoiceTotalMismatch);
+        }
+        invoice.line_items = spec.line_items.clone();
+        invoice.subtotal = subtotal;
+        invoice.discount_amount = discount_amount;
+        invoice.tax_amount = tax_amount;
+    }
+
+    env.storage()
+        .persistent()
+        .set(&DataKey::Invoice(new_invoice_id), &invoice);
+
+    events::publish_invoice_created_event(
+        env,
+        new_invoice_id,
+        merchant_address.clone(),
+        spec.amount,
+        spec.token.clone(),
+    );
+
+    new_invoice_id
+}
+
+fn compute_line_totals(env: &Env, line_items: &Vec<LineItem>) -> (i128, i128, i128) {
+    let mut subtotal = 0;
+    let mut discount_amount = 0;
+    let mut tax_amount = 0;
+
+    for item in line_items.iter() {
+        if item.quantity == 0
+            || item.unit_price < 0
+            || item.tax_basis_points > fee::MAX_BASIS_POINTS
+        {
+            panic_with_error!(env, ContractError::InvalidLineItem);
+        }
+
+        let gross = item.unit_price * item.quantity as i128;
+        if item.discount < 0 || item.discount > gross {
+            panic_with_error!(env, ContractError::InvalidLineItem);
+        }
+
+        let net = gross - item.discount;
+        subtotal += gross;
+        discount_amount += item.discount;
+        tax_amount += net * item.tax_basis_points as i128 / fee::MAX_BASIS_POINTS as i128;
+    }
+
+    (subtotal, discount_amount, tax_amount)
+}
+
+pub fn create_fiat_invoice(
+    env: &Env,
+    merchant_address: &Address,
+    description: &String,
+    fiat_amount: i128,
+    currency: &Symbol,
+    token: &Address,
+    expires_at: Option<u64>,
+) -> u64 {
+    if fiat_amount <= 0 {
+        panic_with_error!(env, ContractError::InvalidAmount);
+    }
+
+    let spec = InvoiceSpec {
+        description: description.clone(),
+        amount: oracle::quote(env, token, fiat_amount, currency),
+        token: token.clone(),
+        expires_at,
+        escrow: false,
+        line_items: Vec::new(env),
+        allowed_payers: Vec::new(env),
+        reference: None,
+        splits: Vec::new(env),
+    };
+    let invoice_id = create_invoice(env, merchant_address, &spec);
+
+    let mut invoice = load_invoice(env, invoice_id).unwrap();
+    invoice.fiat_amount = Some(fiat_amount);
+    invoice.currency = Some(currency.clone());
+    env.storage()
+        .persistent()
+        .set(&DataKey::Invoice(invoice_id), &invoice);
+
+    invoice_id
+}
+
+pub fn record_paid_invoice(
+    env: &Env,
+    merchant_id: u64,
+    description: &String,
+    amount: i128,
+    token: &Address,
+    payer: &Address,
+) -> u64 {
+    let invoice_id = next_invoice_id(env);
+    let timestamp = env.ledger().timestamp();
+
+    let mut invoice = new_invoice(env, invoice_id, merchant_id, description, amount, token);
+    invoice.status = InvoiceStatus::Paid;
+    invoice.payer = Some(payer.clone());
+    invoice.date_paid = Some(timestamp);
+    invoice.amount_paid = amount;
+    invoice.amount_due = 0;
+    invoice.splits = split::default_splits(env, merchant_id);
+
+    env.storage()
+        .persistent()
+        .set(&DataKey::Invoice(invoice_id), &invoice);
+
+    let mut payments = Vec::new(env);
+    payments.push_back(InvoicePayment {
+        payer: payer.clone(),
+        amount,
+        timestamp,
+    });
+    env.storage()
+        .persistent()
+        .set(&DataKey::InvoicePayments(invoice_id), &payments);
+
+    invoice_id
+}
+
+fn validate_allowed_payers(env: &Env, allowed_payers: &Vec<Address>) {
+    if allowed_payers.len() > MAX_ALLOWED_PAYERS {
+        panic_with_error!(env, ContractError::TooManyPayers);
+    }
+    for (index, payer) in allowed_payers.iter().enumerate() {
+        if allowed_payers.last_index_of(&payer) != Some(index as u32) {
+            panic_with_error!(env, ContractError::DuplicatePayer);
+        }
+    }
+}
+
+fn next_invoice_id(env: &Env) -> u64 {
     let invoice_count: u64 = env
         .storage()
         .persistent()
@@ -34,9 +242,23 @@ pub fn create_invoice(
         .unwrap_or(0);
 
     let new_invoice_id = invoice_count + 1;
+    env.storage()
+        .persistent()
+        .set(&DataKey::InvoiceCount, &new_invoice_id);
 
-    let invoice = Invoice {
-        id: new_invoice_id,
+    new_invoice_id
+}
+
+fn new_invoice(
+    env: &Env,
+    invoice_id: u64,
+    merchant_id: u64,
+    description: &String,
+    amount: i128,
+    token: &Address,
+) -> Invoice {
+    Invoice {
+        id: invoice_id,
         description: description.clone(),
         amount,
         token: token.clone(),
@@ -45,33 +267,557 @@ pub fn create_invoice(
         payer: None,
         date_created: env.ledger().timestamp(),
         date_paid: None,
+        expires_at: None,
+        escrow: false,
+        escrow_release_at: None,
+        cancellation_reason: None,
+        amount_paid: 0,
+        amount_due: amount,
+        amount_refunded: 0,
+        fiat_amount: None,
+        currency: None,
+        line_items: Vec::new(env),
+        allowed_payers: Vec::new(env),
+        reference: None,
+        version: 1,
+        splits: Vec::new(env),
+        split_payouts: Vec::new(env),
+        subtotal: amount,
+        discount_amount: 0,
+        tax_amount: 0,
+    }
+}
+
+pub fn get_invoice_by_reference(
+    env: &Env,
+    merchant_address: &Address,
+    reference: &String,
+) -> Invoice {
+    let merchant_id: u64 = env
+        .storage()
+        .persistent()
+        .get(&DataKey::MerchantId(merchant_address.clone()))
+        .unwrap_or_else(|| panic_with_error!(env, ContractError::MerchantNotFound));
+
+    let invoice_id: u64 = env
+        .storage()
+        .persistent()
+        .get(&DataKey::InvoiceReference(merchant_id, reference.clone()))
+        .unwrap_or_else(|| panic_with_error!(env, ContractError::InvoiceNotFound));
+
+    get_invoice(env, invoice_id)
+}
+
+pub fn get_invoice(env: &Env, invoice_id: u64) -> Invoice {
+    let invoice = load_invoice(env, invoice_id)
+        .unwrap_or_else(|| panic_with_error!(env, ContractError::InvoiceNotFound));
+
+    with_expiry(env, invoice)
+}
+
+fn load_invoice(env: &Env, invoice_id: u64) -> Option<Invoice> {
+    let value: Val = env
+        .storage()
+        .persistent()
+        .get(&DataKey::Invoice(invoice_id))?;
+
+    let fields = Map::<Symbol, Val>::try_from_val(env, &value).unwrap();
+    if fields.contains_key(symbol_short!("version")) {
+        return Some(Invoice::try_from_val(env, &value).unwrap());
+    }
+
+    let legacy = LegacyInvoice::try_from_val(env, &value).unwrap();
+    let mut invoice = new_invoice(
+        env,
+        legacy.id,
+        legacy.merchant_id,
+        &legacy.description,
+        legacy.amount,
+        &legacy.token,
+    );
+    invoice.status = legacy.status;
+    invoice.payer = legacy.payer;
+    invoice.date_created = legacy.date_created;
+    invoice.date_paid = legacy.date_paid;
+    match legacy.status {
+        InvoiceStatus::Paid => {
+            invoice.amount_paid = legacy.amount;
+            invoice.amount_due = 0;
+        }
+        InvoiceStatus::Refunded => {
+            invoice.amount_paid = legacy.amount;
+            invoice.amount_refunded = legacy.amount;
+            invoice.amount_due = 0;
+        }
+        InvoiceStatus::Pending => {}
+        _ => invoice.amount_due = 0,
+    }
+    Some(invoice)
+}
+
+fn with_expiry(env: &Env, mut invoice: Invoice) -> Invoice {
+    if invoice.status == InvoiceStatus::Pending && is_past_expiry(env, &invoice) {
+        invoice.status = InvoiceStatus::Expired;
+    }
+    invoice
+}
+
+fn is_past_expiry(env: &Env, invoice: &Invoice) -> bool {
+    match invoice.expires_at {
+        Some(expires_at) => env.ledger().timestamp() >= expires_at,
+        None => false,
+    }
+}
+
+pub fn pay_invoice(env: &Env, payer: &Address, invoice_id: u64) {
+    reentrancy::enter(env);
+    payer.require_auth();
+
+    let invoice = get_invoice(env, invoice_id);
+    apply_payment(env, payer, invoice, None);
+    reentrancy::exit(env);
+}
+
+pub fn pay_invoices(env: &Env, payer: &Address, invoice_ids: &Vec<u64>) {
+    reentrancy::enter(env);
+    payer.require_auth();
+    assert_batch_size(env, invoice_ids.len());
+
+    for invoice_id in invoice_ids.iter() {
+        let invoice = get_invoice(env, invoice_id);
+        apply_payment(env, payer, invoice, None);
+    }
+    reentrancy::exit(env);
+}
+
+pub fn pay_signed_request(
+    env: &Env,
+    payer: &Address,
+    request: &PaymentRequest,
+    signature: &BytesN<64>,
+) -> u64 {
+    reentrancy::enter(env);
+    payer.require_auth();
+
+    if env.ledger().timestamp() >= request.expires_at {
+        panic_with_error!(env, ContractError::PaymentRequestExpired);
+    }
+
+    let nonce_key = DataKey::UsedNonce(request.merchant.clone(), request.nonce);
+    if env.storage().persistent().has(&nonce_key) {
+        panic_with_error!(env, ContractError::NonceAlreadyUsed);
+    }
+
+    let merchant_key = merchant::get_merchant_key(env, &request.merchant);
+    let mut payload = env.current_contract_address().to_xdr(env);
+    payload.append(&request.clone().to_xdr(env));
+    env.crypto()
+        .ed25519_verify(&merchant_key, &payload, signature);
+
+    env.storage().persistent().set(&nonce_key, &true);
+
+    let spec = InvoiceSpec {
+        description: request.description.clone(),
+        amount: request.amount,
+        token: request.token.clone(),
+        expires_at: Some(request.expires_at),
+        escrow: false,
+        line_items: Vec::new(env),
+        allowed_payers: Vec::new(env),
+        reference: None,
+        splits: Vec::new(env),
     };
+    let invoice_id = insert_invoice(env, &request.merchant, &spec);
 
+    let invoice = get_invoice(env, invoice_id);
+    apply_payment(env, payer, invoice, None);
+    reentrancy::exit(env);
+
+    invoice_id
+}
+
+pub fn pay_invoice_partial(env: &Env, payer: &Address, invoice_id: u64, amount: i128) {
+    reentrancy::enter(env);
+    payer.require_auth();
+
+    if amount <= 0 {
+        panic_with_error!(env, ContractError::InvalidAmount);
+    }
+
+    let invoice = get_invoice(env, invoice_id);
+    apply_payment(env, payer, invoice, Some(amount));
+    reentrancy::exit(env);
+}
+
+fn apply_payment(env: &Env, payer: &Address, mut invoice: Invoice, amount: Option<i128>) {
+    match invoice.status {
+        InvoiceStatus::Pending | InvoiceStatus::PartiallyPaid => {}
+        InvoiceStatus::Expired => panic_with_error!(env, ContractError::InvoiceExpired),
+        _ => panic_with_error!(env, ContractError::InvoiceNotPending),
+    }
+    if is_past_expiry(env, &invoice) {
+        panic_with_error!(env, ContractError::InvoiceExpired);
+    }
+    if !invoice.allowed_payers.is_empty() && !invoice.allowed_payers.contains(payer) {
+        panic_with_error!(env, ContractError::PayerNotAllowed);
+    }
+    admin::assert_accepted_token(env, &invoice.token);
+
+    if let (Some(fiat_amount), Some(currency)) = (invoice.fiat_amount, invoice.currency.clone()) {
+        if invoice.amount_paid == 0 {
+            let amount = oracle::quote(env, &invoice.token, fiat_amount, &currency);
+            invoice.amount = amount;
+            invoice.amount_due = amount;
+        }
+    }
+
+    let amount = amount.unwrap_or(invoice.amount_due);
+    if amount > invoice.amount_due {
+        panic_with_error!(env, ContractError::PaymentExceedsBalance);
+    }
+
+    let merchant = merchant::get_merchant(env, invoice.merchant_id);
+    let timestamp = env.ledger().timestamp();
+
+    if invoice.payer.is_none() {
+        invoice.payer = Some(payer.clone());
+    }
+    invoice.amount_paid += amount;
+    invoice.amount_due = invoice.amount - invoice.amount_paid;
+    if invoice.amount_due == 0 {
+        invoice.date_paid = Some(timestamp);
+        if invoice.escrow {
+            invoice.status = InvoiceStatus::Escrowed;
+            invoice.escrow_release_at = Some(timestamp + escrow::get_escrow_release_window(env));
+        } else {
+            invoice.status = InvoiceStatus::Paid;
+        }
+    } else {
+        invoice.status = InvoiceStatus::PartiallyPaid;
+    }
+    let mut payments = get_invoice_payments(env, invoice.id);
+    payments.push_back(InvoicePayment {
+        payer: payer.clone(),
+        amount,
+        timestamp,
+    });
     env.storage()
         .persistent()
-        .set(&DataKey::Invoice(new_invoice_id), &invoice);
+        .set(&DataKey::InvoicePayments(invoice.id), &payments);
+
+    let token_client = token::Client::new(env, &invoice.token);
+    let mut fee_amount = 0;
+    if invoice.escrow {
+        token_client.transfer(payer, env.current_contract_address(), &amount);
+    } else {
+        fee_amount = if invoice.amount_due == 0 {
+            fee::calculate_fee(env, &invoice.token, amount)
+        } else {
+            fee::calculate_basis_points_fee(env, &invoice.token, amount)
+        };
+        split::settle(env, &mut invoice, payer, &merchant, amount - fee_amount);
+        if fee_amount > 0 {
+            token_client.transfer(payer, env.current_contract_address(), &fee_amount);
+            fee::collect_fee(env, &invoice.token, fee_amount);
+        }
+    }
     env.storage()
         .persistent()
-        .set(&DataKey::InvoiceCount, &new_invoice_id);
+        .set(&DataKey::Invoice(invoice.id), &invoice);
 
-    events::publish_invoice_created_event(
+    if invoice.amount_due == 0 {
+        events::publish_invoice_paid_event(
+            env,
+            invoice.id,
+            payer.clone(),
+            merchant.address,
+            amount,
+            fee_amount,
+            invoice.token,
+            timestamp,
+        );
+    } else {
+        events::publish_invoice_partially_paid_event(
+            env,
+            invoice.id,
+            payer.clone(),
+            amount,
+            fee_amount,
+            invoice.amount_paid,
+            timestamp,
+        );
+    }
+}
+
+pub fn get_invoice_payments(env: &Env, invoice_id: u64) -> Vec<InvoicePayment> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::InvoicePayments(invoice_id))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+pub fn cancel_invoice(
+    env: &Env,
+    merchant_address: &Address,
+    invoice_id: u64,
+    reason: CancellationReason,
+) {
+    merchant_address.require_auth();
+
+    let mut invoice = get_invoice(env, invoice_id);
+    assert_invoice_owner(env, merchant_address, &invoice);
+
+    match invoice.status {
+        InvoiceStatus::Pending | InvoiceStatus::Expired => {}
+        InvoiceStatus::Paid | InvoiceStatus::Escrowed | InvoiceStatus::Released => {
+            panic_with_error!(env, ContractError::InvoiceAlreadyPaid)
+        }
+        InvoiceStatus::Refunded => panic_with_error!(env, ContractError::InvoiceAlreadyRefunded),
+        InvoiceStatus::Cancelled => panic_with_error!(env, ContractError::InvoiceAlreadyCancelled),
+        InvoiceStatus::PartiallyPaid => panic_with_error!(env, ContractError::InvoiceNotPending),
+        InvoiceStatus::Disputed => panic_with_error!(env, ContractError::InvoiceDisputed),
+    }
+
+    invoice.status = InvoiceStatus::Cancelled;
+    invoice.cancellation_reason = Some(reason as u32);
+    env.storage()
+        .persistent()
+        .set(&DataKey::Invoice(invoice_id), &invoice);
+
+    events::publish_invoice_cancelled_event(
         env,
-        new_invoice_id,
+        invoice_id,
         merchant_address.clone(),
-        amount,
-        token.clone(),
+        reason,
+        env.ledger().timestamp(),
     );
+}
 
-    new_invoice_id
+pub fn update_invoice(
+    env: &Env,
+    merchant_address: &Address,
+    invoice_id: u64,
+    description: Option<String>,
+    amount: Option<i128>,
+    token: Option<Address>,
+    expiry: Option<ExpiryUpdate>,
+) {
+    merchant_address.require_auth();
+
+    let mut invoice = get_invoice(env, invoice_id);
+    assert_invoice_owner(env, merchant_address, &invoice);
+
+    if invoice.status == InvoiceStatus::Expired {
+        panic_with_error!(env, ContractError::InvoiceExpired);
+    }
+    if invoice.status != InvoiceStatus::Pending {
+        panic_with_error!(env, ContractError::InvoiceNotPending);
+    }
+
+    env.storage().persistent().set(
+        &DataKey::InvoiceVersion(invoice_id, invoice.version),
+        &invoice,
+    );
+
+    if let Some(description) = description {
+        invoice.description = description;
+    }
+
+    if let Some(token) = token {
+        invoice.token = token;
+        if let (Some(fiat_amount), Some(currency)) = (invoice.fiat_amount, invoice.currency.clone())
+        {
+            invoice.amount = oracle::quote(env, &invoice.token, fiat_amount, &currency);
+            invoice.amount_due = invoice.amount;
+        }
+    }
+
+    if let Some(amount) = amount {
+        if amount <= 0 || invoice.currency.is_some() {
+            panic_with_error!(env, ContractError::InvalidAmount);
+        }
+        if !invoice.line_items.is_empty() {
+            panic_with_error!(env, ContractError::InvalidLineItem);
+        }
+        invoice.amount = amount;
+        invoice.amount_due = amount;
+        invoice.subtotal = amount;
+    }
+
+    match expiry {
+        Some(ExpiryUpdate::Set(expires_at)) => {
+            if expires_at <= env.ledger().timestamp() {
+                panic_with_error!(env, ContractError::InvalidExpiry);
+            }
+            invoice.expires_at = Some(expires_at);
+        }
+        Some(ExpiryUpdate::Clear) => invoice.expires_at = None,
+        None => {}
+    }
+
+    admin::assert_accepted_token(env, &invoice.token);
+    admin::assert_within_token_limits(env, &invoice.token, invoice.amount);
+
+    invoice.version += 1;
+    env.storage()
+        .persistent()
+        .set(&DataKey::Invoice(invoice_id), &invoice);
+
+    events::publish_invoice_updated_event(
+        env,
+        invoice_id,
+        invoice.version,
+        invoice.amount,
+        invoice.token,
+        env.ledger().timestamp(),
+    );
 }
 
-pub fn get_invoice(env: &Env, invoice_id: u64) -> Invoice {
+pub fn get_invoice_version(env: &Env, invoice_id: u64, version: u32) -> Invoice {
+    let invoice = get_invoice(env, invoice_id);
+    if version == invoice.version {
+        return invoice;
+    }
+
     env.storage()
         .persistent()
-        .get(&DataKey::Invoice(invoice_id))
+        .get(&DataKey::InvoiceVersion(invoice_id, version))
         .unwrap_or_else(|| panic_with_error!(env, ContractError::InvoiceNotFound))
 }
 
+pub fn refund_invoice(env: &Env, caller: &Address, invoice_id: u64, amount: i128) {
+    reentrancy::enter(env);
+    caller.require_auth();
+
+    let mut invoice = get_invoice(env, invoice_id);
+    let merchant = merchant::get_merchant(env, invoice.merchant_id);
+
+    if *caller != merchant.address && !access_control::has_role(env, caller, Role::Manager) {
+        panic_with_error!(env, ContractError::NotAuthorized);
+    }
+
+    match invoice.status {
+        InvoiceStatus::Paid
+        | InvoiceStatus::PartiallyPaid
+        | InvoiceStatus::Escrowed
+        | InvoiceStatus::Released => {}
+        InvoiceStatus::Refunded => panic_with_error!(env, ContractError::InvoiceAlreadyRefunded),
+        InvoiceStatus::Disputed => panic_with_error!(env, ContractError::InvoiceDisputed),
+        _ => panic_with_error!(env, ContractError::InvoiceNotPaid),
+    }
+
+    if amount <= 0 {
+        panic_with_error!(env, ContractError::InvalidAmount);
+    }
+    if invoice.amount_refunded + amount > invoice.amount_paid {
+        panic_with_error!(env, ContractError::RefundExceedsPaidAmount);
+    }
+
+    let in_custody = escrow::is_in_custody(&invoice);
+    if !in_custody && invoice.amount_refunded + amount > merchant_share(&invoice, &merchant) {
+        panic_with_error!(env, ContractError::RefundExceedsMerchantShare);
+    }
+    let token_client = token::Client::new(env, &invoice.token);
+    let refund_source = merchant.account.clone().unwrap_or(merchant.address.clone());
+    if !in_custody && token_client.balance(&refund_source) < amount {
+        panic_with_error!(env, ContractError::InsufficientFunds);
+    }
+
+    let refunds = refund_shares(env, &invoice, amount);
+    invoice.amount_refunded += amount;
+    if invoice.amount_refunded == invoice.amount_paid {
+        invoice.status = InvoiceStatus::Refunded;
+    }
+    env.storage()
+        .persistent()
+        .set(&DataKey::Invoice(invoice_id), &invoice);
+
+    for (payer, refund) in refunds.iter() {
+        if in_custody {
+            token_client.transfer(&env.current_contract_address(), &payer, &refund);
+        } else if let Some(account) = &merchant.account {
+            merchant_account::MerchantAccountClient::new(env, account).refund(
+                &invoice.token,
+                &payer,
+                &refund,
+            );
+        } else if *caller == merchant.address {
+            token_client.transfer(&merchant.address, &payer, &refund);
+        } else {
+            token_client.transfer_from(
+                &env.current_contract_address(),
+                &merchant.address,
+                &payer,
+                &refund,
+            );
+        }
+
+        events::publish_invoice_refunded_event(
+            env,
+            invoice_id,
+            payer,
+            refund,
+            invoice.amount_refunded,
+            env.ledger().timestamp(),
+        );
+    }
+    reentrancy::exit(env);
+}
+
+// Part of the settled amount the merchant kept. Split payouts to other
+// recipients have already left and cannot be refunded by the merchant.
+pub fn merchant_share(invoice: &Invoice, merchant: &Merchant) -> i128 {
+    let mut paid_out = 0;
+    for payout in invoice.split_payouts.iter() {
+        if payout.recipient != merchant.address
+            && Some(&payout.recipient) != merchant.account.as_ref()
+        {
+            paid_out += payout.amount;
+        }
+    }
+    invoice.amount_paid - paid_out
+}
+
+fn refund_shares(env: &Env, invoice: &Invoice, amount: i128) -> Map<Address, i128> {
+    let first_payer = invoice.payer.clone().unwrap();
+    let mut contributions: Map<Address, i128> = Map::new(env);
+    for payment in get_invoice_payments(env, invoice.id).iter() {
+        let paid = contributions.get(payment.payer.clone()).unwrap_or(0);
+        contributions.set(payment.payer, paid + payment.amount);
+    }
+    if contributions.is_empty() {
+        contributions.set(first_payer.clone(), invoice.amount_paid);
+    }
+
+    let mut shares: Map<Address, i128> = Map::new(env);
+    let mut allocated = 0;
+    for (payer, paid) in contributions.iter() {
+        let share = amount * paid / invoice.amount_paid;
+        if share > 0 {
+            shares.set(payer, share);
+        }
+        allocated += share;
+    }
+
+    let remainder = amount - allocated;
+    if remainder > 0 {
+        let share = shares.get(first_payer.clone()).unwrap_or(0);
+        shares.set(first_payer, share + remainder);
+    }
+    shares
+}
+
+fn assert_invoice_owner(env: &Env, merchant_address: &Address, invoice: &Invoice) {
+    let merchant_id: Option<u64> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::MerchantId(merchant_address.clone()));
+
+    if merchant_id != Some(invoice.merchant_id) {
+        panic_with_error!(env, ContractError::NotAuthorized);
+    }
+}
+
 pub fn get_invoices(env: &Env, filter: InvoiceFilter) -> Vec<Invoice> {
     let invoice_count: u64 = env
         .storage()
@@ -82,11 +828,8 @@ pub fn get_invoices(env: &Env, filter: InvoiceFilter) -> Vec<Invoice> {
     let mut invoices: Vec<Invoice> = Vec::new(env);
 
     for i in 1..=invoice_count {
-        if let Some(invoice) = env
-            .storage()
-            .persistent()
-            .get::<_, Invoice>(&DataKey::Invoice(i))
-        {
+        if let Some(invoice) = load_invoice(env, i) {
+            let invoice = with_expiry(env, invoice);
             let mut matches = true;
 
             if let Some(status) = filter.status {
@@ -121,6 +864,13 @@ pub fn get_invoices(env: &Env, filter: InvoiceFilter) -> Vec<Invoice> {
                 }
             }
 
+            if let Some(payer) = &filter.payer {
+                if !invoice.allowed_payers.contains(payer) && invoice.payer.as_ref() != Some(payer)
+                {
+                    matches = false;
+                }
+            }
+
             if matches {
                 invoices.push_back(invoice);
             }
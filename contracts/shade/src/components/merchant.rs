@@ -1,8 +1,10 @@
-use crate::components::core as core_component;
+use crate::components::{core as core_component, merchant_account};
 use crate::errors::ContractError;
 use crate::events;
-use crate::types::{DataKey, Merchant, MerchantFilter};
-use soroban_sdk::{panic_with_error, Address, BytesN, Env, Vec};
+use crate::types::{DataKey, LegacyMerchant, Merchant, MerchantFilter};
+use soroban_sdk::{
+    panic_with_error, symbol_short, Address, BytesN, Env, Map, Symbol, TryFromVal, Val, Vec,
+};
 
 pub fn register_merchant(env: &Env, merchant: &Address) {
     merchant.require_auth();
@@ -22,6 +24,7 @@ pub fn register_merchant(env: &Env, merchant: &Address) {
         .unwrap_or(0);
 
     let new_id = merchant_count + 1;
+    let account = merchant_account::deploy_account(env, merchant, new_id);
 
     let merchant_data = Merchant {
         id: new_id,
@@ -29,11 +32,10 @@ pub fn register_merchant(env: &Env, merchant: &Address) {
         active: true,
         verified: false,
         date_registered: env.ledger().timestamp(),
+        account,
     };
 
-    env.storage()
-        .persistent()
-        .set(&DataKey::Merchant(new_id), &merchant_data);
+    save_merchant(env, &merchant_data);
     env.storage()
         .persistent()
         .set(&DataKey::MerchantId(merchant.clone()), &new_id);
@@ -64,10 +66,36 @@ pub fn get_merchant(env: &Env, merchant_id: u64) -> Merchant {
         panic_with_error!(env, ContractError::MerchantNotFound);
     }
 
+    load_merchant(env, merchant_id)
+        .unwrap_or_else(|| panic_with_error!(env, ContractError::MerchantNotFound))
+}
+
+pub fn save_merchant(env: &Env, merchant_data: &Merchant) {
     env.storage()
         .persistent()
-        .get(&DataKey::Merchant(merchant_id))
-        .unwrap_or_else(|| panic_with_error!(env, ContractError::MerchantNotFound))
+        .set(&DataKey::Merchant(merchant_data.id), merchant_data);
+}
+
+fn load_merchant(env: &Env, merchant_id: u64) -> Option<Merchant> {
+    let value: Val = env
+        .storage()
+        .persistent()
+        .get(&DataKey::Merchant(merchant_id))?;
+
+    let fields = Map::<Symbol, Val>::try_from_val(env, &value).unwrap();
+    if fields.contains_key(symbol_short!("account")) {
+        return Some(Merchant::try_from_val(env, &value).unwrap());
+    }
+
+    let legacy = LegacyMerchant::try_from_val(env, &value).unwrap();
+    Some(Merchant {
+        id: legacy.id,
+        address: legacy.address,
+        active: legacy.active,
+        verified: legacy.verified,
+        date_registered: legacy.date_registered,
+        account: None,
+    })
 }
 
 pub fn is_merchant(env: &Env, merchant: &Address) -> bool {
@@ -81,10 +109,11 @@ pub fn verify_merchant(env: &Env, admin: &Address, merchant_id: u64, status: boo
 
     let mut merchant_data = get_merchant(env, merchant_id);
     merchant_data.verified = status;
+    save_merchant(env, &merchant_data);
 
-    env.storage()
-        .persistent()
-        .set(&DataKey::Merchant(merchant_id), &merchant_data);
+    if let Some(account) = &merchant_data.account {
+        merchant_account::MerchantAccountClient::new(env, account).set_verified(&status);
+    }
 
     events::publish_merchant_verified_event(env, merchant_id, status, env.ledger().timestamp());
 }
@@ -130,11 +159,7 @@ pub fn get_merchants(env: &Env, filter: MerchantFilter) -> Vec<Merchant> {
     let mut merchants: Vec<Merchant> = Vec::new(env);
 
     for i in 1..=merchant_count {
-        if let Some(merchant) = env
-            .storage()
-            .persistent()
-            .get::<_, Merchant>(&DataKey::Merchant(i))
-        {
+        if let Some(merchant) = load_merchant(env, i) {
             let mut matches = true;
 
             if let Some(active) = filter.is_active {
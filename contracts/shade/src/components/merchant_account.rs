@@ -0,0 +1,137 @@
+use crate::components::{access_control, core as core_component, merchant};
+use crate::errors::ContractError;
+use crate::events;
+use crate::types::{DataKey, Merchant, Role};
+use soroban_sdk::{contractclient, panic_with_error, Address, BytesN, Env, Vec};
+
+#[contractclient(name = "MerchantAccountClient")]
+pub trait MerchantAccount {
+    fn initialize(env: Env, merchant: Address, manager: Address, merchant_id: u64);
+    fn add_token(env: Env, token: Address);
+    fn set_verified(env: Env, verified: bool);
+    fn set_payout_allowlist(env: Env, addresses: Vec<Address>);
+    fn set_withdrawal_limit(env: Env, token: Address, limit: Option<i128>);
+    fn set_withdrawal_delay(env: Env, delay: u64);
+    fn veto_withdrawal(env: Env, withdrawal_id: u64);
+    fn refund(env: Env, token: Address, to: Address, amount: i128);
+}
+
+pub fn set_account_wasm_hash(env: &Env, admin: &Address, wasm_hash: &BytesN<32>) {
+    core_component::assert_admin(env, admin);
+
+    env.storage()
+        .persistent()
+        .set(&DataKey::AccountWasmHash, wasm_hash);
+
+    events::publish_account_wasm_hash_set_event(env, wasm_hash.clone(), env.ledger().timestamp());
+}
+
+pub fn get_account_wasm_hash(env: &Env) -> Option<BytesN<32>> {
+    env.storage().persistent().get(&DataKey::AccountWasmHash)
+}
+
+pub fn deploy_account(env: &Env, merchant: &Address, merchant_id: u64) -> Option<Address> {
+    let wasm_hash = get_account_wasm_hash(env)?;
+
+    let mut salt = [0u8; 32];
+    salt[24..].copy_from_slice(&merchant_id.to_be_bytes());
+    let account = env
+        .deployer()
+        .with_current_contract(BytesN::from_array(env, &salt))
+        .deploy_v2(wasm_hash, ());
+
+    MerchantAccountClient::new(env, &account).initialize(
+        merchant,
+        &env.current_contract_address(),
+        &merchant_id,
+    );
+
+    events::publish_merchant_account_deployed_event(
+        env,
+        merchant.clone(),
+        merchant_id,
+        account.clone(),
+        env.ledger().timestamp(),
+    );
+
+    Some(account)
+}
+
+pub fn deploy_merchant_account(env: &Env, caller: &Address, merchant_id: u64) -> Address {
+    caller.require_auth();
+
+    let mut merchant_data = merchant::get_merchant(env, merchant_id);
+    if *caller != merchant_data.address && *caller != core_component::get_admin(env) {
+        panic_with_error!(env, ContractError::NotAuthorized);
+    }
+    if merchant_data.account.is_some() {
+        panic_with_error!(env, ContractError::MerchantAccountAlreadyDeployed);
+    }
+
+    let account = deploy_account(env, &merchant_data.address, merchant_id)
+        .unwrap_or_else(|| panic_with_error!(env, ContractError::AccountWasmHashNotSet));
+    if merchant_data.verified {
+        MerchantAccountClient::new(env, &account).set_verified(&true);
+    }
+
+    merchant_data.account = Some(account.clone());
+    merchant::save_merchant(env, &merchant_data);
+
+    account
+}
+
+pub fn settlement_address(env: &Env, merchant: &Merchant, token: &Address) -> Address {
+    match &merchant.account {
+        Some(account) => {
+            MerchantAccountClient::new(env, account).add_token(token);
+            account.clone()
+        }
+        None => merchant.address.clone(),
+    }
+}
+
+pub fn set_merchant_payout_allowlist(
+    env: &Env,
+    admin: &Address,
+    merchant_id: u64,
+    addresses: &Vec<Address>,
+) {
+    core_component::assert_admin(env, admin);
+    let account = get_linked_account(env, merchant_id);
+    MerchantAccountClient::new(env, &account).set_payout_allowlist(addresses);
+}
+
+pub fn set_merchant_withdrawal_limit(
+    env: &Env,
+    admin: &Address,
+    merchant_id: u64,
+    token: &Address,
+    limit: &Option<i128>,
+) {
+    core_component::assert_admin(env, admin);
+    let account = get_linked_account(env, merchant_id);
+    MerchantAccountClient::new(env, &account).set_withdrawal_limit(token, limit);
+}
+
+pub fn set_merchant_withdrawal_delay(env: &Env, admin: &Address, merchant_id: u64, delay: u64) {
+    core_component::assert_admin(env, admin);
+    let account = get_linked_account(env, merchant_id);
+    MerchantAccountClient::new(env, &account).set_withdrawal_delay(&delay);
+}
+
+pub fn veto_merchant_withdrawal(
+    env: &Env,
+    operator: &Address,
+    merchant_id: u64,
+    withdrawal_id: u64,
+) {
+    access_control::assert_has_role(env, operator, Role::Operator);
+    let account = get_linked_account(env, merchant_id);
+    MerchantAccountClient::new(env, &account).veto_withdrawal(&withdrawal_id);
+}
+
+fn get_linked_account(env: &Env, merchant_id: u64) -> Address {
+    merchant::get_merchant(env, merchant_id)
+        .account
+        .unwrap_or_else(|| panic_with_error!(env, ContractError::MerchantAccountNotFound))
+}
@@ -1,8 +1,15 @@
 pub mod access_control;
 pub mod admin;
 pub mod core;
+pub mod dispute;
+pub mod escrow;
+pub mod fee;
 pub mod invoice;
 pub mod merchant;
+pub mod merchant_account;
+pub mod oracle;
 pub mod pausable;
 pub mod reentrancy;
+pub mod split;
+pub mod subscription;
 pub mod upgrade;
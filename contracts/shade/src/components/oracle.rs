@@ -0,0 +1,182 @@
+use crate::components::core as core_component;
+use crate::errors::{ContractError, OracleError};
+use crate::events;
+use crate::types::{Asset, DataKey, OracleConfig, PriceData};
+use soroban_sdk::{contractclient, panic_with_error, token, Address, Env, Symbol, Vec};
+
+pub const MAX_DEVIATION_BASIS_POINTS: u32 = 10_000;
+pub const AVERAGE_PRICE_RECORDS: u32 = 5;
+
+#[contractclient(name = "PriceOracleClient")]
+pub trait PriceOracle {
+    fn base(env: Env) -> Asset;
+    fn decimals(env: Env) -> u32;
+    fn lastprice(env: Env, asset: Asset) -> Option<PriceData>;
+    fn prices(env: Env, asset: Asset, records: u32) -> Option<Vec<PriceData>>;
+}
+
+pub fn set_oracle(
+    env: &Env,
+    admin: &Address,
+    oracle: &Address,
+    max_age: u64,
+    max_deviation_basis_points: u32,
+) {
+    core_component::assert_admin(env, admin);
+
+    if max_age == 0 || max_deviation_basis_points > MAX_DEVIATION_BASIS_POINTS {
+        panic_with_error!(env, OracleError::InvalidOracleConfig);
+    }
+
+    let config = OracleConfig {
+        oracle: oracle.clone(),
+        max_age,
+        max_deviation_basis_points,
+    };
+    env.storage()
+        .persistent()
+        .set(&DataKey::OracleConfig, &config);
+
+    events::publish_oracle_set_event(
+        env,
+        oracle.clone(),
+        max_age,
+        max_deviation_basis_points,
+        env.ledger().timestamp(),
+    );
+}
+
+pub fn get_oracle_config(env: &Env) -> OracleConfig {
+    env.storage()
+        .persistent()
+        .get(&DataKey::OracleConfig)
+        .unwrap_or_else(|| panic_with_error!(env, OracleError::OracleNotConfigured))
+}
+
+pub fn quote(env: &Env, token: &Address, fiat_amount: i128, currency: &Symbol) -> i128 {
+    let config = get_oracle_config(env);
+    let client = PriceOracleClient::new(env, &config.oracle);
+    let base = client.base();
+    let oracle_decimals = client.decimals();
+    let token_decimals = token::Client::new(env, token).decimals();
+
+    let fiat_asset = Asset::Other(currency.clone());
+    let token_asset = Asset::Stellar(token.clone());
+
+    let fiat_price = get_price(env, &client, &config, &base, oracle_decimals, &fiat_asset);
+    let token_price = get_price(env, &client, &config, &base, oracle_decimals, &token_asset);
+    let amount = convert(
+        env,
+        fiat_amount,
+        fiat_price,
+        token_price,
+        oracle_decimals,
+        token_decimals,
+    );
+
+    let fiat_average = get_average_price(env, &client, &base, oracle_decimals, &fiat_asset);
+    let token_average = get_average_price(env, &client, &base, oracle_decimals, &token_asset);
+    let average_amount = convert(
+        env,
+        fiat_amount,
+        fiat_average,
+        token_average,
+        oracle_decimals,
+        token_decimals,
+    );
+    assert_within_deviation(env, &config, average_amount, amount);
+
+    amount
+}
+
+// `fiat_amount` carries the oracle's `decimals()` precision; the result is in
+// the token's smallest unit.
+fn convert(
+    env: &Env,
+    fiat_amount: i128,
+    fiat_price: i128,
+    token_price: i128,
+    oracle_decimals: u32,
+    token_decimals: u32,
+) -> i128 {
+    let numerator = fiat_amount
+        .checked_mul(fiat_price)
+        .and_then(|value| value.checked_mul(10i128.checked_pow(token_decimals)?))
+        .unwrap_or_else(|| panic_with_error!(env, ContractError::InvalidAmount));
+    let denominator = token_price
+        .checked_mul(
+            10i128
+                .checked_pow(oracle_decimals)
+                .unwrap_or_else(|| panic_with_error!(env, ContractError::InvalidAmount)),
+        )
+        .unwrap_or_else(|| panic_with_error!(env, ContractError::InvalidAmount));
+
+    let amount = numerator / denominator;
+    if amount <= 0 {
+        panic_with_error!(env, ContractError::InvalidAmount);
+    }
+    amount
+}
+
+fn assert_within_deviation(env: &Env, config: &OracleConfig, average_amount: i128, amount: i128) {
+    let deviation = (amount - average_amount).abs();
+    if deviation * MAX_DEVIATION_BASIS_POINTS as i128
+        > average_amount * config.max_deviation_basis_points as i128
+    {
+        panic_with_error!(env, OracleError::PriceDeviationTooHigh);
+    }
+}
+
+fn get_price(
+    env: &Env,
+    client: &PriceOracleClient,
+    config: &OracleConfig,
+    base: &Asset,
+    decimals: u32,
+    asset: &Asset,
+) -> i128 {
+    if base == asset {
+        return 10i128.pow(decimals);
+    }
+
+    let price_data = client
+        .lastprice(asset)
+        .unwrap_or_else(|| panic_with_error!(env, OracleError::PriceUnavailable));
+
+    if price_data.price <= 0 {
+        panic_with_error!(env, OracleError::PriceUnavailable);
+    }
+    if env.ledger().timestamp() > price_data.timestamp + config.max_age {
+        panic_with_error!(env, OracleError::StalePrice);
+    }
+
+    price_data.price
+}
+
+fn get_average_price(
+    env: &Env,
+    client: &PriceOracleClient,
+    base: &Asset,
+    decimals: u32,
+    asset: &Asset,
+) -> i128 {
+    if base == asset {
+        return 10i128.pow(decimals);
+    }
+
+    let records = client
+        .prices(asset, &AVERAGE_PRICE_RECORDS)
+        .unwrap_or_else(|| panic_with_error!(env, OracleError::PriceUnavailable));
+    if records.is_empty() {
+        panic_with_error!(env, OracleError::PriceUnavailable);
+    }
+
+    let mut total: i128 = 0;
+    for record in records.iter() {
+        if record.price <= 0 {
+            panic_with_error!(env, OracleError::PriceUnavailable);
+        }
+        total += record.price;
+    }
+    total / records.len() as i128
+}
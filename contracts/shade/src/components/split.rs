@@ -0,0 +1,133 @@
+use crate::components::{merchant, merchant_account};
+use crate::errors::ContractError;
+use crate::events;
+use crate::types::{DataKey, Invoice, Merchant, SplitPayout, SplitShare};
+use soroban_sdk::{panic_with_error, token, Address, Env, Vec};
+
+pub const TOTAL_SPLIT_BASIS_POINTS: u32 = 10_000;
+pub const MAX_SPLIT_RECIPIENTS: u32 = 10;
+
+pub fn set_merchant_splits(env: &Env, merchant_address: &Address, splits: &Vec<SplitShare>) {
+    merchant_address.require_auth();
+
+    let merchant_id = get_merchant_id(env, merchant_address);
+    let key = DataKey::MerchantSplits(merchant_id);
+    if splits.is_empty() {
+        env.storage().persistent().remove(&key);
+    } else {
+        validate_splits(env, splits);
+        env.storage().persistent().set(&key, splits);
+    }
+
+    events::publish_merchant_splits_set_event(
+        env,
+        merchant_address.clone(),
+        splits.clone(),
+        env.ledger().timestamp(),
+    );
+}
+
+pub fn get_merchant_splits(env: &Env, merchant_address: &Address) -> Vec<SplitShare> {
+    let merchant_id = get_merchant_id(env, merchant_address);
+    default_splits(env, merchant_id)
+}
+
+pub fn default_splits(env: &Env, merchant_id: u64) -> Vec<SplitShare> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::MerchantSplits(merchant_id))
+        .unwrap_or(Vec::new(env))
+}
+
+pub fn validate_splits(env: &Env, splits: &Vec<SplitShare>) {
+    if splits.is_empty() || splits.len() > MAX_SPLIT_RECIPIENTS {
+        panic_with_error!(env, ContractError::InvalidSplit);
+    }
+
+    let mut total: u32 = 0;
+    for (i, share) in splits.iter().enumerate() {
+        if share.basis_points == 0 || share.basis_points > TOTAL_SPLIT_BASIS_POINTS {
+            panic_with_error!(env, ContractError::InvalidSplit);
+        }
+        for other in splits.iter().skip(i + 1) {
+            if other.recipient == share.recipient {
+                panic_with_error!(env, ContractError::InvalidSplit);
+            }
+        }
+        total += share.basis_points;
+    }
+
+    if total != TOTAL_SPLIT_BASIS_POINTS {
+        panic_with_error!(env, ContractError::InvalidSplit);
+    }
+}
+
+pub fn settle(
+    env: &Env,
+    invoice: &mut Invoice,
+    from: &Address,
+    merchant: &Merchant,
+    amount: i128,
+) -> i128 {
+    let token_client = token::Client::new(env, &invoice.token);
+    let merchant_address = merchant_account::settlement_address(env, merchant, &invoice.token);
+
+    if invoice.splits.is_empty() {
+        if amount > 0 {
+            token_client.transfer(from, &merchant_address, &amount);
+        }
+        return amount;
+    }
+
+    if invoice.split_payouts.is_empty() {
+        for share in invoice.splits.iter() {
+            invoice.split_payouts.push_back(SplitPayout {
+                recipient: share.recipient,
+                amount: 0,
+            });
+        }
+    }
+
+    let mut payouts = Vec::new(env);
+    let mut distributed = 0;
+    for (i, share) in invoice.splits.iter().enumerate() {
+        let payout = amount * share.basis_points as i128 / TOTAL_SPLIT_BASIS_POINTS as i128;
+        if payout > 0 {
+            token_client.transfer(from, &share.recipient, &payout);
+        }
+        distributed += payout;
+
+        let mut total = invoice.split_payouts.get(i as u32).unwrap();
+        total.amount += payout;
+        invoice.split_payouts.set(i as u32, total);
+        payouts.push_back(SplitPayout {
+            recipient: share.recipient,
+            amount: payout,
+        });
+    }
+
+    let merchant_amount = amount - distributed;
+    if merchant_amount > 0 {
+        token_client.transfer(from, &merchant_address, &merchant_amount);
+    }
+
+    events::publish_invoice_split_settled_event(
+        env,
+        invoice.id,
+        payouts,
+        merchant_amount,
+        env.ledger().timestamp(),
+    );
+
+    merchant_amount
+}
+
+fn get_merchant_id(env: &Env, merchant_address: &Address) -> u64 {
+    if !merchant::is_merchant(env, merchant_address) {
+        panic_with_error!(env, ContractError::MerchantNotFound);
+    }
+    env.storage()
+        .persistent()
+        .get(&DataKey::MerchantId(merchant_address.clone()))
+        .unwrap()
+}
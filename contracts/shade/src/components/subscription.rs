@@ -0,0 +1,284 @@
+use crate::components::{admin, fee, invoice, merchant, reentrancy, split};
+use crate::errors::{ContractError, SubscriptionError};
+use crate::events;
+use crate::types::{DataKey, Subscription, SubscriptionPlan, SubscriptionStatus};
+use soroban_sdk::{panic_with_error, token, Address, Env, String};
+
+pub fn create_plan(
+    env: &Env,
+    merchant_address: &Address,
+    description: &String,
+    token: &Address,
+    amount: i128,
+    period: u64,
+    trial_period: u64,
+) -> u64 {
+    merchant_address.require_auth();
+
+    if amount <= 0 {
+        panic_with_error!(env, ContractError::InvalidAmount);
+    }
+    if period == 0 {
+        panic_with_error!(env, SubscriptionError::InvalidPeriod);
+    }
+    admin::assert_accepted_token(env, token);
+
+    let merchant_id: u64 = env
+        .storage()
+        .persistent()
+        .get(&DataKey::MerchantId(merchant_address.clone()))
+        .unwrap_or_else(|| panic_with_error!(env, ContractError::NotAuthorized));
+
+    let plan_count: u64 = env
+        .storage()
+        .persistent()
+        .get(&DataKey::PlanCount)
+        .unwrap_or(0);
+    let plan_id = plan_count + 1;
+
+    let plan = SubscriptionPlan {
+        id: plan_id,
+        merchant_id,
+        description: description.clone(),
+        token: token.clone(),
+        amount,
+        period,
+        trial_period,
+        date_created: env.ledger().timestamp(),
+    };
+
+    env.storage()
+        .persistent()
+        .set(&DataKey::Plan(plan_id), &plan);
+    env.storage()
+        .persistent()
+        .set(&DataKey::PlanCount, &plan_id);
+
+    events::publish_plan_created_event(
+        env,
+        plan_id,
+        merchant_address.clone(),
+        token.clone(),
+        amount,
+        period,
+        env.ledger().timestamp(),
+    );
+
+    plan_id
+}
+
+pub fn get_plan(env: &Env, plan_id: u64) -> SubscriptionPlan {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Plan(plan_id))
+        .unwrap_or_else(|| panic_with_error!(env, SubscriptionError::PlanNotFound))
+}
+
+pub fn subscribe(env: &Env, subscriber: &Address, plan_id: u64) -> u64 {
+    subscriber.require_auth();
+
+    let plan = get_plan(env, plan_id);
+
+    let subscription_count: u64 = env
+        .storage()
+        .persistent()
+        .get(&DataKey::SubscriptionCount)
+        .unwrap_or(0);
+    let subscription_id = subscription_count + 1;
+
+    let timestamp = env.ledger().timestamp();
+    let subscription = Subscription {
+        id: subscription_id,
+        plan_id,
+        subscriber: subscriber.clone(),
+        status: SubscriptionStatus::Active,
+        next_charge_at: timestamp + plan.trial_period,
+        last_charged_at: None,
+        paused_by: None,
+        date_created: timestamp,
+    };
+
+    env.storage()
+        .persistent()
+        .set(&DataKey::Subscription(subscription_id), &subscription);
+    env.storage()
+        .persistent()
+        .set(&DataKey::SubscriptionCount, &subscription_id);
+
+    events::publish_subscription_created_event(
+        env,
+        subscription_id,
+        plan_id,
+        subscriber.clone(),
+        timestamp,
+    );
+
+    subscription_id
+}
+
+pub fn get_subscription(env: &Env, subscription_id: u64) -> Subscription {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Subscription(subscription_id))
+        .unwrap_or_else(|| panic_with_error!(env, SubscriptionError::SubscriptionNotFound))
+}
+
+pub fn charge_subscription(env: &Env, subscription_id: u64) -> Option<u64> {
+    reentrancy::enter(env);
+
+    let mut subscription = get_subscription(env, subscription_id);
+    if subscription.status != SubscriptionStatus::Active {
+        panic_with_error!(env, SubscriptionError::SubscriptionNotActive);
+    }
+
+    let timestamp = env.ledger().timestamp();
+    if timestamp < subscription.next_charge_at {
+        panic_with_error!(env, SubscriptionError::SubscriptionChargeNotDue);
+    }
+
+    let plan = get_plan(env, subscription.plan_id);
+    admin::assert_accepted_token(env, &plan.token);
+    let merchant = merchant::get_merchant(env, plan.merchant_id);
+    let contract_address = env.current_contract_address();
+    let token_client = token::Client::new(env, &plan.token);
+
+    let pulled = token_client.try_transfer_from(
+        &contract_address,
+        &subscription.subscriber,
+        &contract_address,
+        &plan.amount,
+    );
+    if pulled.is_err() {
+        events::publish_subscription_charge_failed_event(
+            env,
+            subscription_id,
+            subscription.subscriber,
+            plan.amount,
+            timestamp,
+        );
+        reentrancy::exit(env);
+        return None;
+    }
+
+    let invoice_id = invoice::record_paid_invoice(
+        env,
+        plan.merchant_id,
+        &plan.description,
+        plan.amount,
+        &plan.token,
+        &subscription.subscriber,
+    );
+
+    subscription.next_charge_at += plan.period;
+    subscription.last_charged_at = Some(timestamp);
+    env.storage()
+        .persistent()
+        .set(&DataKey::Subscription(subscription_id), &subscription);
+
+    let fee_amount = fee::calculate_fee(env, &plan.token, plan.amount);
+    let mut charged_invoice = invoice::get_invoice(env, invoice_id);
+    split::settle(
+        env,
+        &mut charged_invoice,
+        &contract_address,
+        &merchant,
+        plan.amount - fee_amount,
+    );
+    fee::collect_fee(env, &plan.token, fee_amount);
+    env.storage()
+        .persistent()
+        .set(&DataKey::Invoice(invoice_id), &charged_invoice);
+
+    events::publish_subscription_charged_event(
+        env,
+        subscription_id,
+        invoice_id,
+        plan.amount,
+        fee_amount,
+        timestamp,
+    );
+    reentrancy::exit(env);
+
+    Some(invoice_id)
+}
+
+pub fn pause_subscription(env: &Env, caller: &Address, subscription_id: u64) {
+    let mut subscription = get_subscription(env, subscription_id);
+    assert_subscription_party(env, caller, &subscription);
+
+    if subscription.status != SubscriptionStatus::Active {
+        panic_with_error!(env, SubscriptionError::SubscriptionNotActive);
+    }
+
+    subscription.status = SubscriptionStatus::Paused;
+    subscription.paused_by = Some(caller.clone());
+    env.storage()
+        .persistent()
+        .set(&DataKey::Subscription(subscription_id), &subscription);
+
+    events::publish_subscription_paused_event(
+        env,
+        subscription_id,
+        caller.clone(),
+        env.ledger().timestamp(),
+    );
+}
+
+pub fn resume_subscription(env: &Env, caller: &Address, subscription_id: u64) {
+    let mut subscription = get_subscription(env, subscription_id);
+    assert_subscription_party(env, caller, &subscription);
+
+    if subscription.status != SubscriptionStatus::Paused {
+        panic_with_error!(env, SubscriptionError::SubscriptionNotPaused);
+    }
+    if subscription.paused_by != Some(caller.clone()) {
+        panic_with_error!(env, ContractError::NotAuthorized);
+    }
+
+    let timestamp = env.ledger().timestamp();
+    subscription.status = SubscriptionStatus::Active;
+    subscription.paused_by = None;
+    if subscription.next_charge_at < timestamp {
+        subscription.next_charge_at = timestamp;
+    }
+    env.storage()
+        .persistent()
+        .set(&DataKey::Subscription(subscription_id), &subscription);
+
+    events::publish_subscription_resumed_event(env, subscription_id, caller.clone(), timestamp);
+}
+
+pub fn cancel_subscription(env: &Env, caller: &Address, subscription_id: u64) {
+    let mut subscription = get_subscription(env, subscription_id);
+    assert_subscription_party(env, caller, &subscription);
+
+    if subscription.status == SubscriptionStatus::Cancelled {
+        panic_with_error!(env, SubscriptionError::SubscriptionNotActive);
+    }
+
+    subscription.status = SubscriptionStatus::Cancelled;
+    env.storage()
+        .persistent()
+        .set(&DataKey::Subscription(subscription_id), &subscription);
+
+    events::publish_subscription_cancelled_event(
+        env,
+        subscription_id,
+        caller.clone(),
+        env.ledger().timestamp(),
+    );
+}
+
+fn assert_subscription_party(env: &Env, caller: &Address, subscription: &Subscription) {
+    caller.require_auth();
+
+    if *caller == subscription.subscriber {
+        return;
+    }
+
+    let plan = get_plan(env, subscription.plan_id);
+    let merchant = merchant::get_merchant(env, plan.merchant_id);
+    if *caller != merchant.address {
+        panic_with_error!(env, ContractError::NotAuthorized);
+    }
+}
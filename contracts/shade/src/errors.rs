@@ -15,4 +15,62 @@ pub enum ContractError {
     ContractPaused = 9,
     ContractNotPaused = 10,
     MerchantKeyNotFound = 11,
+    InvoiceNotPending = 12,
+    InvalidFee = 13,
+    InvoiceAlreadyPaid = 14,
+    InvoiceAlreadyRefunded = 15,
+    InvoiceAlreadyCancelled = 16,
+    InvoiceNotPaid = 17,
+    RefundExceedsPaidAmount = 18,
+    InsufficientFunds = 19,
+    InvalidExpiry = 20,
+    InvoiceExpired = 21,
+    PaymentExceedsBalance = 22,
+    InvoiceNotEscrowed = 23,
+    EscrowReleaseNotDue = 24,
+    InvalidEscrowWindow = 25,
+    InvoiceNotDisputable = 26,
+    DisputeWindowClosed = 27,
+    DisputeNotFound = 28,
+    InvoiceDisputed = 29,
+    InvalidSplit = 30,
+    InvalidLineItem = 42,
+    InvoiceTotalMismatch = 43,
+    PayerNotAllowed = 44,
+    InvalidBatchSize = 45,
+    DuplicateReference = 46,
+    PaymentRequestExpired = 47,
+    NonceAlreadyUsed = 48,
+    TokenNotAccepted = 49,
+    AmountBelowMinimum = 50,
+    AmountAboveMaximum = 51,
+    MerchantAccountNotFound = 52,
+    TooManyPayers = 53,
+    DuplicatePayer = 54,
+    RefundExceedsMerchantShare = 55,
+    MerchantAccountAlreadyDeployed = 56,
+    AccountWasmHashNotSet = 57,
+}
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum SubscriptionError {
+    PlanNotFound = 31,
+    SubscriptionNotFound = 32,
+    SubscriptionNotActive = 33,
+    SubscriptionChargeNotDue = 34,
+    InvalidPeriod = 35,
+    SubscriptionNotPaused = 36,
+}
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum OracleError {
+    OracleNotConfigured = 37,
+    InvalidOracleConfig = 38,
+    PriceUnavailable = 39,
+    StalePrice = 40,
+    PriceDeviationTooHigh = 41,
 }
@@ -1,4 +1,4 @@
-use soroban_sdk::{contractevent, Address, BytesN, Env};
+use soroban_sdk::{contractevent, Address, BytesN, Env, Vec};
 
 #[contractevent]
 pub struct InitalizedEvent {
@@ -51,6 +51,44 @@ pub fn publish_merchant_registered_event(
     .publish(env);
 }
 
+#[contractevent]
+pub struct MerchantAccountDeployedEvent {
+    pub merchant: Address,
+    pub merchant_id: u64,
+    pub account: Address,
+    pub timestamp: u64,
+}
+
+pub fn publish_merchant_account_deployed_event(
+    env: &Env,
+    merchant: Address,
+    merchant_id: u64,
+    account: Address,
+    timestamp: u64,
+) {
+    MerchantAccountDeployedEvent {
+        merchant,
+        merchant_id,
+        account,
+        timestamp,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct AccountWasmHashSetEvent {
+    pub wasm_hash: BytesN<32>,
+    pub timestamp: u64,
+}
+
+pub fn publish_account_wasm_hash_set_event(env: &Env, wasm_hash: BytesN<32>, timestamp: u64) {
+    AccountWasmHashSetEvent {
+        wasm_hash,
+        timestamp,
+    }
+    .publish(env);
+}
+
 #[contractevent]
 pub struct InvoiceCreatedEvent {
     pub invoice_id: u64,
@@ -75,6 +113,291 @@ pub fn publish_invoice_created_event(
     .publish(env);
 }
 
+#[contractevent]
+pub struct InvoicePaidEvent {
+    pub invoice_id: u64,
+    pub payer: Address,
+    pub merchant: Address,
+    pub amount: i128,
+    pub fee: i128,
+    pub token: Address,
+    pub timestamp: u64,
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn publish_invoice_paid_event(
+    env: &Env,
+    invoice_id: u64,
+    payer: Address,
+    merchant: Address,
+    amount: i128,
+    fee: i128,
+    token: Address,
+    timestamp: u64,
+) {
+    InvoicePaidEvent {
+        invoice_id,
+        payer,
+        merchant,
+        amount,
+        fee,
+        token,
+        timestamp,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct InvoicePartiallyPaidEvent {
+    pub invoice_id: u64,
+    pub payer: Address,
+    pub amount: i128,
+    pub fee: i128,
+    pub amount_paid: i128,
+    pub timestamp: u64,
+}
+
+pub fn publish_invoice_partially_paid_event(
+    env: &Env,
+    invoice_id: u64,
+    payer: Address,
+    amount: i128,
+    fee: i128,
+    amount_paid: i128,
+    timestamp: u64,
+) {
+    InvoicePartiallyPaidEvent {
+        invoice_id,
+        payer,
+        amount,
+        fee,
+        amount_paid,
+        timestamp,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct InvoiceUpdatedEvent {
+    pub invoice_id: u64,
+    pub version: u32,
+    pub amount: i128,
+    pub token: Address,
+    pub timestamp: u64,
+}
+
+pub fn publish_invoice_updated_event(
+    env: &Env,
+    invoice_id: u64,
+    version: u32,
+    amount: i128,
+    token: Address,
+    timestamp: u64,
+) {
+    InvoiceUpdatedEvent {
+        invoice_id,
+        version,
+        amount,
+        token,
+        timestamp,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct InvoiceCancelledEvent {
+    pub invoice_id: u64,
+    pub merchant: Address,
+    pub reason: crate::types::CancellationReason,
+    pub timestamp: u64,
+}
+
+pub fn publish_invoice_cancelled_event(
+    env: &Env,
+    invoice_id: u64,
+    merchant: Address,
+    reason: crate::types::CancellationReason,
+    timestamp: u64,
+) {
+    InvoiceCancelledEvent {
+        invoice_id,
+        merchant,
+        reason,
+        timestamp,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct InvoiceRefundedEvent {
+    pub invoice_id: u64,
+    pub payer: Address,
+    pub amount: i128,
+    pub amount_refunded: i128,
+    pub timestamp: u64,
+}
+
+pub fn publish_invoice_refunded_event(
+    env: &Env,
+    invoice_id: u64,
+    payer: Address,
+    amount: i128,
+    amount_refunded: i128,
+    timestamp: u64,
+) {
+    InvoiceRefundedEvent {
+        invoice_id,
+        payer,
+        amount,
+        amount_refunded,
+        timestamp,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct EscrowReleasedEvent {
+    pub invoice_id: u64,
+    pub merchant: Address,
+    pub amount: i128,
+    pub fee: i128,
+    pub timestamp: u64,
+}
+
+pub fn publish_escrow_released_event(
+    env: &Env,
+    invoice_id: u64,
+    merchant: Address,
+    amount: i128,
+    fee: i128,
+    timestamp: u64,
+) {
+    EscrowReleasedEvent {
+        invoice_id,
+        merchant,
+        amount,
+        fee,
+        timestamp,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct MerchantSplitsSetEvent {
+    pub merchant: Address,
+    pub splits: Vec<crate::types::SplitShare>,
+    pub timestamp: u64,
+}
+
+pub fn publish_merchant_splits_set_event(
+    env: &Env,
+    merchant: Address,
+    splits: Vec<crate::types::SplitShare>,
+    timestamp: u64,
+) {
+    MerchantSplitsSetEvent {
+        merchant,
+        splits,
+        timestamp,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct InvoiceSplitSettledEvent {
+    pub invoice_id: u64,
+    pub payouts: Vec<crate::types::SplitPayout>,
+    pub merchant_amount: i128,
+    pub timestamp: u64,
+}
+
+pub fn publish_invoice_split_settled_event(
+    env: &Env,
+    invoice_id: u64,
+    payouts: Vec<crate::types::SplitPayout>,
+    merchant_amount: i128,
+    timestamp: u64,
+) {
+    InvoiceSplitSettledEvent {
+        invoice_id,
+        payouts,
+        merchant_amount,
+        timestamp,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct EscrowReleaseWindowSetEvent {
+    pub window: u64,
+    pub timestamp: u64,
+}
+
+pub fn publish_escrow_release_window_set_event(env: &Env, window: u64, timestamp: u64) {
+    EscrowReleaseWindowSetEvent { window, timestamp }.publish(env);
+}
+
+#[contractevent]
+pub struct DisputeWindowSetEvent {
+    pub window: u64,
+    pub timestamp: u64,
+}
+
+pub fn publish_dispute_window_set_event(env: &Env, window: u64, timestamp: u64) {
+    DisputeWindowSetEvent { window, timestamp }.publish(env);
+}
+
+#[contractevent]
+pub struct DisputeOpenedEvent {
+    pub invoice_id: u64,
+    pub payer: Address,
+    pub evidence_hash: BytesN<32>,
+    pub timestamp: u64,
+}
+
+pub fn publish_dispute_opened_event(
+    env: &Env,
+    invoice_id: u64,
+    payer: Address,
+    evidence_hash: BytesN<32>,
+    timestamp: u64,
+) {
+    DisputeOpenedEvent {
+        invoice_id,
+        payer,
+        evidence_hash,
+        timestamp,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct DisputeResolvedEvent {
+    pub invoice_id: u64,
+    pub operator: Address,
+    pub payer_amount: i128,
+    pub merchant_amount: i128,
+    pub timestamp: u64,
+}
+
+pub fn publish_dispute_resolved_event(
+    env: &Env,
+    invoice_id: u64,
+    operator: Address,
+    payer_amount: i128,
+    merchant_amount: i128,
+    timestamp: u64,
+) {
+    DisputeResolvedEvent {
+        invoice_id,
+        operator,
+        payer_amount,
+        merchant_amount,
+        timestamp,
+    }
+    .publish(env);
+}
+
 #[contractevent]
 pub struct MerchantVerifiedEvent {
     pub merchant_id: u64,
@@ -194,3 +517,267 @@ pub fn publish_contract_upgraded_event(
     }
     .publish(env);
 }
+
+#[contractevent]
+pub struct TokenFeeSetEvent {
+    pub token: Address,
+    pub fee_in_basis_points: u32,
+    pub fee_amount: i128,
+    pub timestamp: u64,
+}
+
+pub fn publish_token_fee_set_event(
+    env: &Env,
+    token: Address,
+    fee_in_basis_points: u32,
+    fee_amount: i128,
+    timestamp: u64,
+) {
+    TokenFeeSetEvent {
+        token,
+        fee_in_basis_points,
+        fee_amount,
+        timestamp,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct FeesWithdrawnEvent {
+    pub token: Address,
+    pub amount: i128,
+    pub recipient: Address,
+    pub timestamp: u64,
+}
+
+pub fn publish_fees_withdrawn_event(
+    env: &Env,
+    token: Address,
+    amount: i128,
+    recipient: Address,
+    timestamp: u64,
+) {
+    FeesWithdrawnEvent {
+        token,
+        amount,
+        recipient,
+        timestamp,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct PlanCreatedEvent {
+    pub plan_id: u64,
+    pub merchant: Address,
+    pub token: Address,
+    pub amount: i128,
+    pub period: u64,
+    pub timestamp: u64,
+}
+
+pub fn publish_plan_created_event(
+    env: &Env,
+    plan_id: u64,
+    merchant: Address,
+    token: Address,
+    amount: i128,
+    period: u64,
+    timestamp: u64,
+) {
+    PlanCreatedEvent {
+        plan_id,
+        merchant,
+        token,
+        amount,
+        period,
+        timestamp,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct SubscriptionCreatedEvent {
+    pub subscription_id: u64,
+    pub plan_id: u64,
+    pub subscriber: Address,
+    pub timestamp: u64,
+}
+
+pub fn publish_subscription_created_event(
+    env: &Env,
+    subscription_id: u64,
+    plan_id: u64,
+    subscriber: Address,
+    timestamp: u64,
+) {
+    SubscriptionCreatedEvent {
+        subscription_id,
+        plan_id,
+        subscriber,
+        timestamp,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct SubscriptionChargedEvent {
+    pub subscription_id: u64,
+    pub invoice_id: u64,
+    pub amount: i128,
+    pub fee: i128,
+    pub timestamp: u64,
+}
+
+pub fn publish_subscription_charged_event(
+    env: &Env,
+    subscription_id: u64,
+    invoice_id: u64,
+    amount: i128,
+    fee: i128,
+    timestamp: u64,
+) {
+    SubscriptionChargedEvent {
+        subscription_id,
+        invoice_id,
+        amount,
+        fee,
+        timestamp,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct SubscriptionChargeFailedEvent {
+    pub subscription_id: u64,
+    pub subscriber: Address,
+    pub amount: i128,
+    pub timestamp: u64,
+}
+
+pub fn publish_subscription_charge_failed_event(
+    env: &Env,
+    subscription_id: u64,
+    subscriber: Address,
+    amount: i128,
+    timestamp: u64,
+) {
+    SubscriptionChargeFailedEvent {
+        subscription_id,
+        subscriber,
+        amount,
+        timestamp,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct SubscriptionPausedEvent {
+    pub subscription_id: u64,
+    pub caller: Address,
+    pub timestamp: u64,
+}
+
+pub fn publish_subscription_paused_event(
+    env: &Env,
+    subscription_id: u64,
+    caller: Address,
+    timestamp: u64,
+) {
+    SubscriptionPausedEvent {
+        subscription_id,
+        caller,
+        timestamp,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct SubscriptionResumedEvent {
+    pub subscription_id: u64,
+    pub caller: Address,
+    pub timestamp: u64,
+}
+
+pub fn publish_subscription_resumed_event(
+    env: &Env,
+    subscription_id: u64,
+    caller: Address,
+    timestamp: u64,
+) {
+    SubscriptionResumedEvent {
+        subscription_id,
+        caller,
+        timestamp,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct SubscriptionCancelledEvent {
+    pub subscription_id: u64,
+    pub caller: Address,
+    pub timestamp: u64,
+}
+
+pub fn publish_subscription_cancelled_event(
+    env: &Env,
+    subscription_id: u64,
+    caller: Address,
+    timestamp: u64,
+) {
+    SubscriptionCancelledEvent {
+        subscription_id,
+        caller,
+        timestamp,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct OracleSetEvent {
+    pub oracle: Address,
+    pub max_age: u64,
+    pub max_deviation_basis_points: u32,
+    pub timestamp: u64,
+}
+
+pub fn publish_oracle_set_event(
+    env: &Env,
+    oracle: Address,
+    max_age: u64,
+    max_deviation_basis_points: u32,
+    timestamp: u64,
+) {
+    OracleSetEvent {
+        oracle,
+        max_age,
+        max_deviation_basis_points,
+        timestamp,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct TokenLimitsSetEvent {
+    pub token: Address,
+    pub min_amount: Option<i128>,
+    pub max_amount: Option<i128>,
+    pub timestamp: u64,
+}
+
+pub fn publish_token_limits_set_event(
+    env: &Env,
+    token: Address,
+    min_amount: Option<i128>,
+    max_amount: Option<i128>,
+    timestamp: u64,
+) {
+    TokenLimitsSetEvent {
+        token,
+        min_amount,
+        max_amount,
+        timestamp,
+    }
+    .publish(env);
+}
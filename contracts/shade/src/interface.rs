@@ -1,5 +1,9 @@
-use crate::types::{Invoice, InvoiceFilter, Merchant, MerchantFilter, Role};
-use soroban_sdk::{contracttrait, Address, BytesN, Env, String, Vec};
+use crate::types::{
+    CancellationReason, Dispute, ExpiryUpdate, Invoice, InvoiceFilter, InvoicePayment, InvoiceSpec,
+    Merchant, MerchantFilter, OracleConfig, PaymentRequest, Role, SplitShare, Subscription,
+    SubscriptionPlan, TokenFee, TokenLimits,
+};
+use soroban_sdk::{contracttrait, Address, BytesN, Env, String, Symbol, Vec};
 
 #[contracttrait]
 pub trait ShadeTrait {
@@ -8,22 +12,102 @@ pub trait ShadeTrait {
     fn add_accepted_token(env: Env, admin: Address, token: Address);
     fn remove_accepted_token(env: Env, admin: Address, token: Address);
     fn is_accepted_token(env: Env, token: Address) -> bool;
+    fn set_token_limits(
+        env: Env,
+        admin: Address,
+        token: Address,
+        min_amount: Option<i128>,
+        max_amount: Option<i128>,
+    );
+    fn get_token_limits(env: Env, token: Address) -> TokenLimits;
+    fn set_account_wasm_hash(env: Env, admin: Address, wasm_hash: BytesN<32>);
+    fn get_account_wasm_hash(env: Env) -> Option<BytesN<32>>;
     fn register_merchant(env: Env, merchant: Address);
+    fn deploy_merchant_account(env: Env, caller: Address, merchant_id: u64) -> Address;
+    fn set_merchant_payout_allowlist(
+        env: Env,
+        admin: Address,
+        merchant_id: u64,
+        addresses: Vec<Address>,
+    );
+    fn set_merchant_withdrawal_limit(
+        env: Env,
+        admin: Address,
+        merchant_id: u64,
+        token: Address,
+        limit: Option<i128>,
+    );
+    fn set_merchant_withdrawal_delay(env: Env, admin: Address, merchant_id: u64, delay: u64);
+    fn veto_merchant_withdrawal(env: Env, operator: Address, merchant_id: u64, withdrawal_id: u64);
     fn get_merchant(env: Env, merchant_id: u64) -> Merchant;
     fn get_merchants(env: Env, filter: MerchantFilter) -> Vec<Merchant>;
     fn is_merchant(env: Env, merchant: Address) -> bool;
     fn verify_merchant(env: Env, admin: Address, merchant_id: u64, status: bool);
     fn is_merchant_verified(env: Env, merchant_id: u64) -> bool;
-    fn create_invoice(
+    fn create_invoice(env: Env, merchant: Address, spec: InvoiceSpec) -> u64;
+    fn create_invoices(env: Env, merchant: Address, specs: Vec<InvoiceSpec>) -> Vec<u64>;
+    fn create_fiat_invoice(
         env: Env,
         merchant: Address,
         description: String,
-        amount: i128,
+        fiat_amount: i128,
+        currency: Symbol,
         token: Address,
+        expires_at: Option<u64>,
     ) -> u64;
     fn get_invoice(env: Env, invoice_id: u64) -> Invoice;
+    fn get_invoice_by_reference(env: Env, merchant: Address, reference: String) -> Invoice;
+    fn pay_invoice(env: Env, payer: Address, invoice_id: u64);
+    fn pay_invoices(env: Env, payer: Address, invoice_ids: Vec<u64>);
+    fn pay_signed_request(
+        env: Env,
+        payer: Address,
+        request: PaymentRequest,
+        signature: BytesN<64>,
+    ) -> u64;
+    fn pay_invoice_partial(env: Env, payer: Address, invoice_id: u64, amount: i128);
+    fn get_invoice_payments(env: Env, invoice_id: u64) -> Vec<InvoicePayment>;
+    fn confirm_delivery(env: Env, payer: Address, invoice_id: u64);
+    fn release_escrow(env: Env, invoice_id: u64);
+    fn set_escrow_release_window(env: Env, admin: Address, window: u64);
+    fn get_escrow_release_window(env: Env) -> u64;
+    fn set_dispute_window(env: Env, admin: Address, window: u64);
+    fn get_dispute_window(env: Env) -> u64;
+    fn open_dispute(env: Env, payer: Address, invoice_id: u64, evidence_hash: BytesN<32>);
+    fn resolve_dispute(env: Env, operator: Address, invoice_id: u64, payer_share_basis_points: u32);
+    fn get_dispute(env: Env, invoice_id: u64) -> Dispute;
+    fn create_plan(
+        env: Env,
+        merchant: Address,
+        description: String,
+        token: Address,
+        amount: i128,
+        period: u64,
+        trial_period: u64,
+    ) -> u64;
+    fn get_plan(env: Env, plan_id: u64) -> SubscriptionPlan;
+    fn subscribe(env: Env, subscriber: Address, plan_id: u64) -> u64;
+    fn get_subscription(env: Env, subscription_id: u64) -> Subscription;
+    fn charge_subscription(env: Env, subscription_id: u64) -> Option<u64>;
+    fn pause_subscription(env: Env, caller: Address, subscription_id: u64);
+    fn resume_subscription(env: Env, caller: Address, subscription_id: u64);
+    fn cancel_subscription(env: Env, caller: Address, subscription_id: u64);
+    fn cancel_invoice(env: Env, merchant: Address, invoice_id: u64, reason: CancellationReason);
+    fn refund_invoice(env: Env, caller: Address, invoice_id: u64, amount: i128);
+    fn update_invoice(
+        env: Env,
+        merchant: Address,
+        invoice_id: u64,
+        description: Option<String>,
+        amount: Option<i128>,
+        token: Option<Address>,
+        expiry: Option<ExpiryUpdate>,
+    );
+    fn get_invoice_version(env: Env, invoice_id: u64, version: u32) -> Invoice;
     fn set_merchant_key(env: Env, merchant: Address, key: BytesN<32>);
     fn get_merchant_key(env: Env, merchant: Address) -> BytesN<32>;
+    fn set_merchant_splits(env: Env, merchant: Address, splits: Vec<SplitShare>);
+    fn get_merchant_splits(env: Env, merchant: Address) -> Vec<SplitShare>;
     fn grant_role(env: Env, admin: Address, user: Address, role: Role);
     fn revoke_role(env: Env, admin: Address, user: Address, role: Role);
     fn has_role(env: Env, user: Address, role: Role) -> bool;
@@ -31,6 +115,24 @@ pub trait ShadeTrait {
     fn pause(env: Env, admin: Address);
     fn unpause(env: Env, admin: Address);
     fn is_paused(env: Env) -> bool;
+    fn set_token_fee(
+        env: Env,
+        admin: Address,
+        token: Address,
+        fee_in_basis_points: u32,
+        fee_amount: i128,
+    );
+    fn get_token_fee(env: Env, token: Address) -> TokenFee;
+    fn get_collected_fees(env: Env, token: Address) -> i128;
+    fn withdraw_fees(env: Env, admin: Address, token: Address, treasury: Address);
+    fn set_oracle(
+        env: Env,
+        admin: Address,
+        oracle: Address,
+        max_age: u64,
+        max_deviation_basis_points: u32,
+    );
+    fn get_oracle_config(env: Env) -> OracleConfig;
 
     fn upgrade(env: Env, new_wasm_hash: soroban_sdk::BytesN<32>);
 }
@@ -1,13 +1,22 @@
 use crate::components::{
     access_control as access_control_component, admin as admin_component, core as core_component,
-    invoice as invoice_component, merchant as merchant_component, pausable as pausable_component,
-    upgrade as upgrade_component,
+    dispute as dispute_component, escrow as escrow_component, fee as fee_component,
+    invoice as invoice_component, merchant as merchant_component,
+    merchant_account as merchant_account_component, oracle as oracle_component,
+    pausable as pausable_component, split as split_component,
+    subscription as subscription_component, upgrade as upgrade_component,
 };
 use crate::errors::ContractError;
 use crate::events;
 use crate::interface::ShadeTrait;
-use crate::types::{ContractInfo, DataKey, Invoice, InvoiceFilter, Merchant, MerchantFilter, Role};
-use soroban_sdk::{contract, contractimpl, panic_with_error, Address, BytesN, Env, String, Vec};
+use crate::types::{
+    CancellationReason, ContractInfo, DataKey, Dispute, ExpiryUpdate, Invoice, InvoiceFilter,
+    InvoicePayment, InvoiceSpec, Merchant, MerchantFilter, OracleConfig, PaymentRequest, Role,
+    SplitShare, Subscription, SubscriptionPlan, TokenFee, TokenLimits,
+};
+use soroban_sdk::{
+    contract, contractimpl, panic_with_error, Address, BytesN, Env, String, Symbol, Vec,
+};
 
 #[contract]
 pub struct Shade;
@@ -46,11 +55,81 @@ impl ShadeTrait for Shade {
         admin_component::is_accepted_token(&env, &token)
     }
 
+    fn set_token_limits(
+        env: Env,
+        admin: Address,
+        token: Address,
+        min_amount: Option<i128>,
+        max_amount: Option<i128>,
+    ) {
+        admin_component::set_token_limits(&env, &admin, &token, min_amount, max_amount);
+    }
+
+    fn get_token_limits(env: Env, token: Address) -> TokenLimits {
+        admin_component::get_token_limits(&env, &token)
+    }
+
+    fn set_account_wasm_hash(env: Env, admin: Address, wasm_hash: BytesN<32>) {
+        merchant_account_component::set_account_wasm_hash(&env, &admin, &wasm_hash);
+    }
+
+    fn get_account_wasm_hash(env: Env) -> Option<BytesN<32>> {
+        merchant_account_component::get_account_wasm_hash(&env)
+    }
+
     fn register_merchant(env: Env, merchant: Address) {
         pausable_component::assert_not_paused(&env);
         merchant_component::register_merchant(&env, &merchant);
     }
 
+    fn deploy_merchant_account(env: Env, caller: Address, merchant_id: u64) -> Address {
+        pausable_component::assert_not_paused(&env);
+        merchant_account_component::deploy_merchant_account(&env, &caller, merchant_id)
+    }
+
+    fn set_merchant_payout_allowlist(
+        env: Env,
+        admin: Address,
+        merchant_id: u64,
+        addresses: Vec<Address>,
+    ) {
+        merchant_account_component::set_merchant_payout_allowlist(
+            &env,
+            &admin,
+            merchant_id,
+            &addresses,
+        );
+    }
+
+    fn set_merchant_withdrawal_limit(
+        env: Env,
+        admin: Address,
+        merchant_id: u64,
+        token: Address,
+        limit: Option<i128>,
+    ) {
+        merchant_account_component::set_merchant_withdrawal_limit(
+            &env,
+            &admin,
+            merchant_id,
+            &token,
+            &limit,
+        );
+    }
+
+    fn set_merchant_withdrawal_delay(env: Env, admin: Address, merchant_id: u64, delay: u64) {
+        merchant_account_component::set_merchant_withdrawal_delay(&env, &admin, merchant_id, delay);
+    }
+
+    fn veto_merchant_withdrawal(env: Env, operator: Address, merchant_id: u64, withdrawal_id: u64) {
+        merchant_account_component::veto_merchant_withdrawal(
+            &env,
+            &operator,
+            merchant_id,
+            withdrawal_id,
+        );
+    }
+
     fn get_merchant(env: Env, merchant_id: u64) -> Merchant {
         merchant_component::get_merchant(&env, merchant_id)
     }
@@ -71,21 +150,208 @@ impl ShadeTrait for Shade {
         merchant_component::is_merchant_verified(&env, merchant_id)
     }
 
-    fn create_invoice(
+    fn create_invoice(env: Env, merchant: Address, spec: InvoiceSpec) -> u64 {
+        pausable_component::assert_not_paused(&env);
+        invoice_component::create_invoice(&env, &merchant, &spec)
+    }
+
+    fn create_invoices(env: Env, merchant: Address, specs: Vec<InvoiceSpec>) -> Vec<u64> {
+        pausable_component::assert_not_paused(&env);
+        invoice_component::create_invoices(&env, &merchant, &specs)
+    }
+
+    fn create_fiat_invoice(
         env: Env,
         merchant: Address,
         description: String,
-        amount: i128,
+        fiat_amount: i128,
+        currency: Symbol,
         token: Address,
+        expires_at: Option<u64>,
     ) -> u64 {
         pausable_component::assert_not_paused(&env);
-        invoice_component::create_invoice(&env, &merchant, &description, amount, &token)
+        invoice_component::create_fiat_invoice(
+            &env,
+            &merchant,
+            &description,
+            fiat_amount,
+            &currency,
+            &token,
+            expires_at,
+        )
     }
 
     fn get_invoice(env: Env, invoice_id: u64) -> Invoice {
         invoice_component::get_invoice(&env, invoice_id)
     }
 
+    fn get_invoice_by_reference(env: Env, merchant: Address, reference: String) -> Invoice {
+        invoice_component::get_invoice_by_reference(&env, &merchant, &reference)
+    }
+
+    fn pay_invoice(env: Env, payer: Address, invoice_id: u64) {
+        pausable_component::assert_not_paused(&env);
+        invoice_component::pay_invoice(&env, &payer, invoice_id);
+    }
+
+    fn pay_invoices(env: Env, payer: Address, invoice_ids: Vec<u64>) {
+        pausable_component::assert_not_paused(&env);
+        invoice_component::pay_invoices(&env, &payer, &invoice_ids);
+    }
+
+    fn pay_signed_request(
+        env: Env,
+        payer: Address,
+        request: PaymentRequest,
+        signature: BytesN<64>,
+    ) -> u64 {
+        pausable_component::assert_not_paused(&env);
+        invoice_component::pay_signed_request(&env, &payer, &request, &signature)
+    }
+
+    fn pay_invoice_partial(env: Env, payer: Address, invoice_id: u64, amount: i128) {
+        pausable_component::assert_not_paused(&env);
+        invoice_component::pay_invoice_partial(&env, &payer, invoice_id, amount);
+    }
+
+    fn get_invoice_payments(env: Env, invoice_id: u64) -> Vec<InvoicePayment> {
+        invoice_component::get_invoice_payments(&env, invoice_id)
+    }
+
+    fn confirm_delivery(env: Env, payer: Address, invoice_id: u64) {
+        pausable_component::assert_not_paused(&env);
+        escrow_component::confirm_delivery(&env, &payer, invoice_id);
+    }
+
+    fn release_escrow(env: Env, invoice_id: u64) {
+        pausable_component::assert_not_paused(&env);
+        escrow_component::release_escrow(&env, invoice_id);
+    }
+
+    fn set_escrow_release_window(env: Env, admin: Address, window: u64) {
+        escrow_component::set_escrow_release_window(&env, &admin, window);
+    }
+
+    fn get_escrow_release_window(env: Env) -> u64 {
+        escrow_component::get_escrow_release_window(&env)
+    }
+
+    fn set_dispute_window(env: Env, admin: Address, window: u64) {
+        dispute_component::set_dispute_window(&env, &admin, window);
+    }
+
+    fn get_dispute_window(env: Env) -> u64 {
+        dispute_component::get_dispute_window(&env)
+    }
+
+    fn open_dispute(env: Env, payer: Address, invoice_id: u64, evidence_hash: BytesN<32>) {
+        pausable_component::assert_not_paused(&env);
+        dispute_component::open_dispute(&env, &payer, invoice_id, &evidence_hash);
+    }
+
+    fn resolve_dispute(
+        env: Env,
+        operator: Address,
+        invoice_id: u64,
+        payer_share_basis_points: u32,
+    ) {
+        pausable_component::assert_not_paused(&env);
+        dispute_component::resolve_dispute(&env, &operator, invoice_id, payer_share_basis_points);
+    }
+
+    fn get_dispute(env: Env, invoice_id: u64) -> Dispute {
+        dispute_component::get_dispute(&env, invoice_id)
+    }
+
+    fn create_plan(
+        env: Env,
+        merchant: Address,
+        description: String,
+        token: Address,
+        amount: i128,
+        period: u64,
+        trial_period: u64,
+    ) -> u64 {
+        pausable_component::assert_not_paused(&env);
+        subscription_component::create_plan(
+            &env,
+            &merchant,
+            &description,
+            &token,
+            amount,
+            period,
+            trial_period,
+        )
+    }
+
+    fn get_plan(env: Env, plan_id: u64) -> SubscriptionPlan {
+        subscription_component::get_plan(&env, plan_id)
+    }
+
+    fn subscribe(env: Env, subscriber: Address, plan_id: u64) -> u64 {
+        pausable_component::assert_not_paused(&env);
+        subscription_component::subscribe(&env, &subscriber, plan_id)
+    }
+
+    fn get_subscription(env: Env, subscription_id: u64) -> Subscription {
+        subscription_component::get_subscription(&env, subscription_id)
+    }
+
+    fn charge_subscription(env: Env, subscription_id: u64) -> Option<u64> {
+        pausable_component::assert_not_paused(&env);
+        subscription_component::charge_subscription(&env, subscription_id)
+    }
+
+    fn pause_subscription(env: Env, caller: Address, subscription_id: u64) {
+        pausable_component::assert_not_paused(&env);
+        subscription_component::pause_subscription(&env, &caller, subscription_id);
+    }
+
+    fn resume_subscription(env: Env, caller: Address, subscription_id: u64) {
+        pausable_component::assert_not_paused(&env);
+        subscription_component::resume_subscription(&env, &caller, subscription_id);
+    }
+
+    fn cancel_subscription(env: Env, caller: Address, subscription_id: u64) {
+        pausable_component::assert_not_paused(&env);
+        subscription_component::cancel_subscription(&env, &caller, subscription_id);
+    }
+
+    fn cancel_invoice(env: Env, merchant: Address, invoice_id: u64, reason: CancellationReason) {
+        pausable_component::assert_not_paused(&env);
+        invoice_component::cancel_invoice(&env, &merchant, invoice_id, reason);
+    }
+
+    fn refund_invoice(env: Env, caller: Address, invoice_id: u64, amount: i128) {
+        pausable_component::assert_not_paused(&env);
+        invoice_component::refund_invoice(&env, &caller, invoice_id, amount);
+    }
+
+    fn update_invoice(
+        env: Env,
+        merchant: Address,
+        invoice_id: u64,
+        description: Option<String>,
+        amount: Option<i128>,
+        token: Option<Address>,
+        expiry: Option<ExpiryUpdate>,
+    ) {
+        pausable_component::assert_not_paused(&env);
+        invoice_component::update_invoice(
+            &env,
+            &merchant,
+            invoice_id,
+            description,
+            amount,
+            token,
+            expiry,
+        );
+    }
+
+    fn get_invoice_version(env: Env, invoice_id: u64, version: u32) -> Invoice {
+        invoice_component::get_invoice_version(&env, invoice_id, version)
+    }
+
     fn set_merchant_key(env: Env, merchant: Address, key: BytesN<32>) {
         merchant_component::set_merchant_key(&env, &merchant, &key);
     }
@@ -94,6 +360,14 @@ impl ShadeTrait for Shade {
         merchant_component::get_merchant_key(&env, &merchant)
     }
 
+    fn set_merchant_splits(env: Env, merchant: Address, splits: Vec<SplitShare>) {
+        split_component::set_merchant_splits(&env, &merchant, &splits);
+    }
+
+    fn get_merchant_splits(env: Env, merchant: Address) -> Vec<SplitShare> {
+        split_component::get_merchant_splits(&env, &merchant)
+    }
+
     fn grant_role(env: Env, admin: Address, user: Address, role: Role) {
         access_control_component::grant_role(&env, &admin, &user, role);
     }
@@ -122,6 +396,44 @@ impl ShadeTrait for Shade {
         pausable_component::is_paused(&env)
     }
 
+    fn set_token_fee(
+        env: Env,
+        admin: Address,
+        token: Address,
+        fee_in_basis_points: u32,
+        fee_amount: i128,
+    ) {
+        pausable_component::assert_not_paused(&env);
+        fee_component::set_token_fee(&env, &admin, &token, fee_in_basis_points, fee_amount);
+    }
+
+    fn get_token_fee(env: Env, token: Address) -> TokenFee {
+        fee_component::get_token_fee(&env, &token)
+    }
+
+    fn get_collected_fees(env: Env, token: Address) -> i128 {
+        fee_component::get_collected_fees(&env, &token)
+    }
+
+    fn withdraw_fees(env: Env, admin: Address, token: Address, treasury: Address) {
+        pausable_component::assert_not_paused(&env);
+        fee_component::withdraw_fees(&env, &admin, &token, &treasury);
+    }
+
+    fn set_oracle(
+        env: Env,
+        admin: Address,
+        oracle: Address,
+        max_age: u64,
+        max_deviation_basis_points: u32,
+    ) {
+        oracle_component::set_oracle(&env, &admin, &oracle, max_age, max_deviation_basis_points);
+    }
+
+    fn get_oracle_config(env: Env) -> OracleConfig {
+        oracle_component::get_oracle_config(&env)
+    }
+
     fn upgrade(env: Env, new_wasm_hash: soroban_sdk::BytesN<32>) {
         upgrade_component::upgrade(&env, &new_wasm_hash);
     }
@@ -1,5 +1,21 @@
 pub mod test;
 pub mod test_accepted_tokens;
+pub mod test_batch;
+pub mod test_cancel_invoice;
+pub mod test_dispute;
+pub mod test_escrow;
+pub mod test_fees;
+pub mod test_fiat_invoice;
 pub mod test_invoice;
+pub mod test_invoice_expiry;
+pub mod test_invoice_payers;
+pub mod test_merchant_account;
 pub mod test_merchant_key;
+pub mod test_partial_payment;
+pub mod test_pay_invoice;
+pub mod test_refund;
+pub mod test_signed_request;
+pub mod test_splits;
+pub mod test_subscription;
+pub mod test_update_invoice;
 pub mod test_upgrade;
@@ -4,8 +4,9 @@ use crate::components::admin as admin_component;
 use crate::errors::ContractError;
 use crate::shade::Shade;
 use crate::shade::ShadeClient;
+use crate::types::{InvoiceSpec, InvoiceStatus};
 use soroban_sdk::testutils::{Address as _, Events as _};
-use soroban_sdk::{Address, Env, Map, Symbol, TryIntoVal, Val};
+use soroban_sdk::{token, Address, Env, Map, String, Symbol, TryIntoVal, Val, Vec};
 
 fn assert_latest_token_event(
     env: &Env,
@@ -15,7 +16,7 @@ fn assert_latest_token_event(
     expected_timestamp: u64,
 ) {
     let events = env.events().all();
-    assert!(events.len() > 0);
+    assert!(!events.is_empty());
 
     let (event_contract_id, topics, data) = events.get(events.len() - 1).unwrap();
     assert_eq!(event_contract_id, contract_id.clone());
@@ -173,3 +174,100 @@ fn test_invalid_token_address_panics() {
     let invalid_token = Address::generate(&env);
     client.add_accepted_token(&admin, &invalid_token);
 }
+
+fn setup_invoice_test() -> (Env, ShadeClient<'static>, Address, Address, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(Shade, ());
+    let client = ShadeClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let merchant = Address::generate(&env);
+    client.register_merchant(&merchant);
+
+    let token_admin = Address::generate(&env);
+    let token = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+
+    (env, client, admin, merchant, token)
+}
+
+fn invoice_spec(env: &Env, token: &Address, amount: i128) -> InvoiceSpec {
+    InvoiceSpec {
+        description: String::from_str(env, "Order"),
+        amount,
+        token: token.clone(),
+        expires_at: None,
+        escrow: false,
+        line_items: Vec::new(env),
+        allowed_payers: Vec::new(env),
+        reference: None,
+        splits: Vec::new(env),
+    }
+}
+
+#[should_panic(expected = "HostError: Error(Contract, #49)")]
+#[test]
+fn test_create_invoice_with_unaccepted_token() {
+    let (env, client, _admin, merchant, token) = setup_invoice_test();
+    client.create_invoice(&merchant, &invoice_spec(&env, &token, 1000));
+}
+
+#[test]
+fn test_token_limits_enforced_on_create() {
+    let (env, client, admin, merchant, token) = setup_invoice_test();
+    client.add_accepted_token(&admin, &token);
+    client.set_token_limits(&admin, &token, &Some(100), &Some(5_000));
+
+    let limits = client.get_token_limits(&token);
+    assert_eq!(limits.min_amount, Some(100));
+    assert_eq!(limits.max_amount, Some(5_000));
+
+    let below = client.try_create_invoice(&merchant, &invoice_spec(&env, &token, 99));
+    let expected_error =
+        soroban_sdk::Error::from_contract_error(ContractError::AmountBelowMinimum as u32);
+    assert!(matches!(below, Err(Ok(err)) if err == expected_error));
+
+    let above = client.try_create_invoice(&merchant, &invoice_spec(&env, &token, 5_001));
+    let expected_error =
+        soroban_sdk::Error::from_contract_error(ContractError::AmountAboveMaximum as u32);
+    assert!(matches!(above, Err(Ok(err)) if err == expected_error));
+
+    client.create_invoice(&merchant, &invoice_spec(&env, &token, 5_000));
+}
+
+#[should_panic(expected = "HostError: Error(Contract, #7)")]
+#[test]
+fn test_set_token_limits_max_below_min() {
+    let (_env, client, admin, _merchant, token) = setup_invoice_test();
+    client.set_token_limits(&admin, &token, &Some(500), &Some(100));
+}
+
+#[test]
+fn test_pending_invoice_unpayable_after_token_removed() {
+    let (env, client, admin, merchant, token) = setup_invoice_test();
+    client.add_accepted_token(&admin, &token);
+
+    let payer = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&payer, &1000);
+
+    let invoice_id = client.create_invoice(&merchant, &invoice_spec(&env, &token, 1000));
+    client.remove_accepted_token(&admin, &token);
+
+    let result = client.try_pay_invoice(&payer, &invoice_id);
+    let expected_error =
+        soroban_sdk::Error::from_contract_error(ContractError::TokenNotAccepted as u32);
+    assert!(matches!(result, Err(Ok(err)) if err == expected_error));
+    assert_eq!(
+        client.get_invoice(&invoice_id).status,
+        InvoiceStatus::Pending
+    );
+
+    client.add_accepted_token(&admin, &token);
+    client.pay_invoice(&payer, &invoice_id);
+    assert_eq!(client.get_invoice(&invoice_id).status, InvoiceStatus::Paid);
+}
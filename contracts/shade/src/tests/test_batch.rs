@@ -0,0 +1,136 @@
+#![cfg(test)]
+
+use crate::shade::{Shade, ShadeClient};
+use crate::types::{InvoiceSpec, InvoiceStatus};
+use soroban_sdk::testutils::{Address as _, Events as _};
+use soroban_sdk::{token, vec, Address, Env, String, Symbol, TryIntoVal, Vec};
+
+fn setup_test() -> (Env, ShadeClient<'static>, Address, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(Shade, ());
+    let client = ShadeClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+    (env, client, contract_id, admin)
+}
+
+fn setup_merchant(env: &Env, client: &ShadeClient) -> (Address, Address) {
+    let merchant = Address::generate(env);
+    client.register_merchant(&merchant);
+
+    let token_admin = Address::generate(env);
+    let token = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    client.add_accepted_token(&client.get_admin(), &token);
+
+    (merchant, token)
+}
+
+fn spec(env: &Env, description: &str, amount: i128, token: &Address) -> InvoiceSpec {
+    InvoiceSpec {
+        description: String::from_str(env, description),
+        amount,
+        token: token.clone(),
+        expires_at: None,
+        escrow: false,
+        line_items: Vec::new(env),
+        allowed_payers: Vec::new(env),
+        reference: None,
+        splits: Vec::new(env),
+    }
+}
+
+fn count_events(env: &Env, name: &str) -> u32 {
+    let mut count = 0;
+    for (_contract_id, topics, _data) in env.events().all().iter() {
+        let event_name: Symbol = topics.get(0).unwrap().try_into_val(env).unwrap();
+        if event_name == Symbol::new(env, name) {
+            count += 1;
+        }
+    }
+    count
+}
+
+#[test]
+fn test_create_invoices_batch() {
+    let (env, client, _contract_id, _admin) = setup_test();
+    let (merchant, token) = setup_merchant(&env, &client);
+
+    let specs = vec![
+        &env,
+        spec(&env, "Seat 1", 100, &token),
+        spec(&env, "Seat 2", 200, &token),
+        spec(&env, "Seat 3", 300, &token),
+    ];
+    let invoice_ids = client.create_invoices(&merchant, &specs);
+
+    assert_eq!(invoice_ids, vec![&env, 1, 2, 3]);
+    assert_eq!(count_events(&env, "invoice_created_event"), 3);
+    assert_eq!(client.get_invoice(&2).amount, 200);
+}
+
+#[should_panic(expected = "HostError: Error(Contract, #7)")]
+#[test]
+fn test_create_invoices_rejects_whole_batch() {
+    let (env, client, _contract_id, _admin) = setup_test();
+    let (merchant, token) = setup_merchant(&env, &client);
+
+    let specs = vec![
+        &env,
+        spec(&env, "Seat 1", 100, &token),
+        spec(&env, "Seat 2", 0, &token),
+    ];
+    client.create_invoices(&merchant, &specs);
+}
+
+#[should_panic(expected = "HostError: Error(Contract, #45)")]
+#[test]
+fn test_create_invoices_empty_batch() {
+    let (env, client, _contract_id, _admin) = setup_test();
+    let (merchant, _token) = setup_merchant(&env, &client);
+
+    client.create_invoices(&merchant, &Vec::new(&env));
+}
+
+#[test]
+fn test_pay_invoices_across_merchants_and_tokens() {
+    let (env, client, _contract_id, _admin) = setup_test();
+    let (merchant_a, token_a) = setup_merchant(&env, &client);
+    let (merchant_b, token_b) = setup_merchant(&env, &client);
+
+    let payer = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token_a).mint(&payer, &500);
+    token::StellarAssetClient::new(&env, &token_b).mint(&payer, &700);
+
+    let id_a = client.create_invoice(&merchant_a, &spec(&env, "A", 500, &token_a));
+    let id_b = client.create_invoice(&merchant_b, &spec(&env, "B", 700, &token_b));
+
+    client.pay_invoices(&payer, &vec![&env, id_a, id_b]);
+    assert_eq!(count_events(&env, "invoice_paid_event"), 2);
+
+    assert_eq!(client.get_invoice(&id_a).status, InvoiceStatus::Paid);
+    assert_eq!(client.get_invoice(&id_b).status, InvoiceStatus::Paid);
+    assert_eq!(token::Client::new(&env, &token_a).balance(&merchant_a), 500);
+    assert_eq!(token::Client::new(&env, &token_b).balance(&merchant_b), 700);
+}
+
+#[test]
+fn test_pay_invoices_is_all_or_nothing() {
+    let (env, client, _contract_id, _admin) = setup_test();
+    let (merchant, token) = setup_merchant(&env, &client);
+
+    let payer = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&payer, &1000);
+
+    let open_id = client.create_invoice(&merchant, &spec(&env, "Open", 300, &token));
+    let paid_id = client.create_invoice(&merchant, &spec(&env, "Paid", 200, &token));
+    client.pay_invoice(&payer, &paid_id);
+
+    let result = client.try_pay_invoices(&payer, &vec![&env, open_id, paid_id]);
+    assert!(result.is_err());
+
+    assert_eq!(client.get_invoice(&open_id).status, InvoiceStatus::Pending);
+    assert_eq!(token::Client::new(&env, &token).balance(&payer), 800);
+}
@@ -0,0 +1,130 @@
+#![cfg(test)]
+
+use crate::shade::{Shade, ShadeClient};
+use crate::types::{CancellationReason, InvoiceSpec, InvoiceStatus};
+use soroban_sdk::testutils::{Address as _, Events as _};
+use soroban_sdk::{token, Address, Env, Map, String, Symbol, TryIntoVal, Val, Vec};
+
+fn setup_test() -> (Env, ShadeClient<'static>, Address, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(Shade, ());
+    let client = ShadeClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+    (env, client, contract_id, admin)
+}
+
+fn setup_invoice(env: &Env, client: &ShadeClient) -> (Address, Address, u64) {
+    let merchant = Address::generate(env);
+    client.register_merchant(&merchant);
+
+    let token_admin = Address::generate(env);
+    let token = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    client.add_accepted_token(&client.get_admin(), &token);
+
+    let invoice_id = client.create_invoice(
+        &merchant,
+        &InvoiceSpec {
+            description: String::from_str(env, "Test Invoice"),
+            amount: 1000,
+            token: token.clone(),
+            expires_at: None,
+            escrow: false,
+            line_items: Vec::new(env),
+            allowed_payers: Vec::new(env),
+            reference: None,
+            splits: Vec::new(env),
+        },
+    );
+
+    (merchant, token, invoice_id)
+}
+
+#[test]
+fn test_cancel_invoice_success() {
+    let (env, client, contract_id, _admin) = setup_test();
+    let (merchant, _token, invoice_id) = setup_invoice(&env, &client);
+
+    client.cancel_invoice(&merchant, &invoice_id, &CancellationReason::Duplicate);
+
+    let events = env.events().all();
+    let (event_contract_id, topics, data) = events.get(events.len() - 1).unwrap();
+    assert_eq!(event_contract_id, contract_id);
+
+    let event_name: Symbol = topics.get(0).unwrap().try_into_val(&env).unwrap();
+    assert_eq!(event_name, Symbol::new(&env, "invoice_cancelled_event"));
+
+    let data_map: Map<Symbol, Val> = data.try_into_val(&env).unwrap();
+    let reason_in_event: CancellationReason = data_map
+        .get(Symbol::new(&env, "reason"))
+        .unwrap()
+        .try_into_val(&env)
+        .unwrap();
+    assert_eq!(reason_in_event, CancellationReason::Duplicate);
+
+    let invoice = client.get_invoice(&invoice_id);
+    assert_eq!(invoice.status, InvoiceStatus::Cancelled);
+    assert_eq!(
+        invoice.cancellation_reason,
+        Some(CancellationReason::Duplicate as u32)
+    );
+}
+
+#[should_panic(expected = "HostError: Error(Contract, #1)")]
+#[test]
+fn test_cancel_invoice_by_other_merchant() {
+    let (env, client, _contract_id, _admin) = setup_test();
+    let (_merchant, _token, invoice_id) = setup_invoice(&env, &client);
+
+    let other_merchant = Address::generate(&env);
+    client.register_merchant(&other_merchant);
+
+    client.cancel_invoice(&other_merchant, &invoice_id, &CancellationReason::Other);
+}
+
+#[should_panic(expected = "HostError: Error(Contract, #14)")]
+#[test]
+fn test_cancel_paid_invoice() {
+    let (env, client, _contract_id, _admin) = setup_test();
+    let (merchant, token, invoice_id) = setup_invoice(&env, &client);
+
+    let payer = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&payer, &1000);
+    client.pay_invoice(&payer, &invoice_id);
+
+    client.cancel_invoice(&merchant, &invoice_id, &CancellationReason::CustomerRequest);
+}
+
+#[should_panic(expected = "HostError: Error(Contract, #16)")]
+#[test]
+fn test_cancel_invoice_twice() {
+    let (env, client, _contract_id, _admin) = setup_test();
+    let (merchant, _token, invoice_id) = setup_invoice(&env, &client);
+
+    client.cancel_invoice(
+        &merchant,
+        &invoice_id,
+        &CancellationReason::IncorrectDetails,
+    );
+    client.cancel_invoice(
+        &merchant,
+        &invoice_id,
+        &CancellationReason::IncorrectDetails,
+    );
+}
+
+#[should_panic(expected = "HostError: Error(Contract, #12)")]
+#[test]
+fn test_pay_cancelled_invoice() {
+    let (env, client, _contract_id, _admin) = setup_test();
+    let (merchant, token, invoice_id) = setup_invoice(&env, &client);
+
+    client.cancel_invoice(&merchant, &invoice_id, &CancellationReason::Duplicate);
+
+    let payer = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&payer, &1000);
+    client.pay_invoice(&payer, &invoice_id);
+}
@@ -0,0 +1,241 @@
+#![cfg(test)]
+
+use crate::components::escrow::DEFAULT_ESCROW_RELEASE_WINDOW;
+use crate::shade::{Shade, ShadeClient};
+use crate::types::{InvoiceSpec, InvoiceStatus, Role};
+use soroban_sdk::testutils::{Address as _, Events as _, Ledger};
+use soroban_sdk::{token, Address, BytesN, Env, String, Symbol, TryIntoVal, Vec};
+
+const MERCHANT_ACCOUNT_WASM: &[u8] = include_bytes!("fixtures/merchant_account.wasm");
+
+fn setup_test() -> (Env, ShadeClient<'static>, Address, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+    let contract_id = env.register(Shade, ());
+    let client = ShadeClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+    (env, client, contract_id, admin)
+}
+
+fn setup_paid_invoice(
+    env: &Env,
+    client: &ShadeClient,
+    escrow: bool,
+) -> (Address, Address, Address, u64) {
+    let merchant = Address::generate(env);
+    client.register_merchant(&merchant);
+
+    let token_admin = Address::generate(env);
+    let token = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    client.add_accepted_token(&client.get_admin(), &token);
+
+    let payer = Address::generate(env);
+    token::StellarAssetClient::new(env, &token).mint(&payer, &1000);
+
+    let invoice_id = client.create_invoice(
+        &merchant,
+        &InvoiceSpec {
+            description: String::from_str(env, "Order"),
+            amount: 1000,
+            token: token.clone(),
+            expires_at: None,
+            escrow,
+            line_items: Vec::new(env),
+            allowed_payers: Vec::new(env),
+            reference: None,
+            splits: Vec::new(env),
+        },
+    );
+    client.pay_invoice(&payer, &invoice_id);
+
+    (merchant, payer, token, invoice_id)
+}
+
+fn setup_paid_invoice_with_account(
+    env: &Env,
+    client: &ShadeClient,
+    admin: &Address,
+) -> (Address, Address, Address, u64) {
+    let wasm_hash = env.deployer().upload_contract_wasm(MERCHANT_ACCOUNT_WASM);
+    client.set_account_wasm_hash(admin, &wasm_hash);
+
+    let (_merchant, payer, token, invoice_id) = setup_paid_invoice(env, client, false);
+    let account = client.get_merchant(&1).account.unwrap();
+    (account, payer, token, invoice_id)
+}
+
+fn setup_operator(env: &Env, client: &ShadeClient, admin: &Address) -> Address {
+    let operator = Address::generate(env);
+    client.grant_role(admin, &operator, &Role::Operator);
+    operator
+}
+
+#[test]
+fn test_open_dispute_freezes_escrow() {
+    let (env, client, _contract_id, _admin) = setup_test();
+    let (_merchant, payer, _token, invoice_id) = setup_paid_invoice(&env, &client, true);
+
+    let evidence = BytesN::from_array(&env, &[7u8; 32]);
+    client.open_dispute(&payer, &invoice_id, &evidence);
+
+    let events = env.events().all();
+    let (_event_contract_id, topics, _data) = events.get(events.len() - 1).unwrap();
+    let event_name: Symbol = topics.get(0).unwrap().try_into_val(&env).unwrap();
+    assert_eq!(event_name, Symbol::new(&env, "dispute_opened_event"));
+
+    assert_eq!(
+        client.get_invoice(&invoice_id).status,
+        InvoiceStatus::Disputed
+    );
+
+    let dispute = client.get_dispute(&invoice_id);
+    assert_eq!(dispute.payer, payer);
+    assert_eq!(dispute.evidence_hash, evidence);
+    assert_eq!(dispute.opened_at, 1_000);
+    assert!(!dispute.resolved);
+
+    assert!(client.try_confirm_delivery(&payer, &invoice_id).is_err());
+}
+
+#[test]
+fn test_operator_splits_escrowed_funds() {
+    let (env, client, contract_id, admin) = setup_test();
+    let (merchant, payer, token, invoice_id) = setup_paid_invoice(&env, &client, true);
+    let operator = setup_operator(&env, &client, &admin);
+
+    client.open_dispute(&payer, &invoice_id, &BytesN::from_array(&env, &[1u8; 32]));
+    client.resolve_dispute(&operator, &invoice_id, &3_000);
+
+    let events = env.events().all();
+    let (_event_contract_id, topics, _data) = events.get(events.len() - 1).unwrap();
+    let event_name: Symbol = topics.get(0).unwrap().try_into_val(&env).unwrap();
+    assert_eq!(event_name, Symbol::new(&env, "dispute_resolved_event"));
+
+    let dispute = client.get_dispute(&invoice_id);
+    assert!(dispute.resolved);
+    assert_eq!(dispute.resolver, Some(operator));
+    assert_eq!(dispute.payer_amount, 300);
+    assert_eq!(dispute.merchant_amount, 700);
+
+    let invoice = client.get_invoice(&invoice_id);
+    assert_eq!(invoice.status, InvoiceStatus::Released);
+    assert_eq!(invoice.amount_refunded, 300);
+
+    let token_client = token::Client::new(&env, &token);
+    assert_eq!(token_client.balance(&payer), 300);
+    assert_eq!(token_client.balance(&merchant), 700);
+    assert_eq!(token_client.balance(&contract_id), 0);
+}
+
+#[test]
+fn test_full_award_to_payer_refunds_invoice() {
+    let (env, client, _contract_id, admin) = setup_test();
+    let (_merchant, payer, token, invoice_id) = setup_paid_invoice(&env, &client, true);
+    let operator = setup_operator(&env, &client, &admin);
+
+    client.open_dispute(&payer, &invoice_id, &BytesN::from_array(&env, &[1u8; 32]));
+    client.resolve_dispute(&operator, &invoice_id, &10_000);
+
+    assert_eq!(
+        client.get_invoice(&invoice_id).status,
+        InvoiceStatus::Refunded
+    );
+    assert_eq!(token::Client::new(&env, &token).balance(&payer), 1000);
+}
+
+#[should_panic(expected = "HostError: Error(Contract, #26)")]
+#[test]
+fn test_paid_invoice_not_disputable() {
+    let (env, client, _contract_id, _admin) = setup_test();
+    let (_merchant, payer, _token, invoice_id) = setup_paid_invoice(&env, &client, false);
+
+    client.open_dispute(&payer, &invoice_id, &BytesN::from_array(&env, &[2u8; 32]));
+}
+
+#[should_panic(expected = "HostError: Error(Contract, #1)")]
+#[test]
+fn test_resolve_dispute_requires_operator() {
+    let (env, client, _contract_id, _admin) = setup_test();
+    let (_merchant, payer, _token, invoice_id) = setup_paid_invoice(&env, &client, true);
+
+    client.open_dispute(&payer, &invoice_id, &BytesN::from_array(&env, &[1u8; 32]));
+    client.resolve_dispute(&Address::generate(&env), &invoice_id, &5_000);
+}
+
+#[should_panic(expected = "HostError: Error(Contract, #27)")]
+#[test]
+fn test_open_dispute_after_window() {
+    let (env, client, _contract_id, _admin) = setup_test();
+    let (_merchant, payer, _token, invoice_id) = setup_paid_invoice(&env, &client, true);
+
+    env.ledger()
+        .set_timestamp(1_000 + DEFAULT_ESCROW_RELEASE_WINDOW);
+    client.open_dispute(&payer, &invoice_id, &BytesN::from_array(&env, &[1u8; 32]));
+}
+
+#[should_panic(expected = "HostError: Error(Contract, #28)")]
+#[test]
+fn test_get_dispute_not_found() {
+    let (_env, client, _contract_id, _admin) = setup_test();
+    client.get_dispute(&1);
+}
+
+#[test]
+fn test_paid_invoice_dispute_freezes_account_funds() {
+    let (env, client, contract_id, admin) = setup_test();
+    let (account, payer, token, invoice_id) =
+        setup_paid_invoice_with_account(&env, &client, &admin);
+
+    let token_client = token::Client::new(&env, &token);
+    assert_eq!(token_client.balance(&account), 1000);
+
+    client.open_dispute(&payer, &invoice_id, &BytesN::from_array(&env, &[3u8; 32]));
+
+    assert_eq!(token_client.balance(&account), 0);
+    assert_eq!(token_client.balance(&contract_id), 1000);
+    assert_eq!(client.get_dispute(&invoice_id).amount, 1000);
+    assert_eq!(
+        client.get_invoice(&invoice_id).status,
+        InvoiceStatus::Disputed
+    );
+    assert!(client
+        .try_refund_invoice(&client.get_merchant(&1).address, &invoice_id, &100)
+        .is_err());
+}
+
+#[test]
+fn test_operator_splits_frozen_account_funds() {
+    let (env, client, contract_id, admin) = setup_test();
+    let (account, payer, token, invoice_id) =
+        setup_paid_invoice_with_account(&env, &client, &admin);
+    let operator = setup_operator(&env, &client, &admin);
+
+    client.open_dispute(&payer, &invoice_id, &BytesN::from_array(&env, &[3u8; 32]));
+    client.resolve_dispute(&operator, &invoice_id, &3_000);
+
+    let token_client = token::Client::new(&env, &token);
+    assert_eq!(token_client.balance(&payer), 300);
+    assert_eq!(token_client.balance(&account), 700);
+    assert_eq!(token_client.balance(&contract_id), 0);
+
+    let invoice = client.get_invoice(&invoice_id);
+    assert_eq!(invoice.status, InvoiceStatus::Paid);
+    assert_eq!(invoice.amount_refunded, 300);
+}
+
+#[should_panic(expected = "HostError: Error(Contract, #27)")]
+#[test]
+fn test_paid_invoice_dispute_after_window() {
+    let (env, client, _contract_id, admin) = setup_test();
+    client.set_dispute_window(&admin, &3_600);
+    assert_eq!(client.get_dispute_window(), 3_600);
+    let (_account, payer, _token, invoice_id) =
+        setup_paid_invoice_with_account(&env, &client, &admin);
+
+    env.ledger().set_timestamp(1_000 + 3_600);
+    client.open_dispute(&payer, &invoice_id, &BytesN::from_array(&env, &[3u8; 32]));
+}
@@ -0,0 +1,184 @@
+#![cfg(test)]
+
+use crate::components::escrow::DEFAULT_ESCROW_RELEASE_WINDOW;
+use crate::shade::{Shade, ShadeClient};
+use crate::types::{InvoiceSpec, InvoiceStatus};
+use soroban_sdk::testutils::{Address as _, Events as _, Ledger};
+use soroban_sdk::{token, Address, Env, Map, String, Symbol, TryIntoVal, Val, Vec};
+
+fn setup_test() -> (Env, ShadeClient<'static>, Address, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+    let contract_id = env.register(Shade, ());
+    let client = ShadeClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+    (env, client, contract_id, admin)
+}
+
+fn setup_escrowed_invoice(env: &Env, client: &ShadeClient) -> (Address, Address, Address, u64) {
+    let merchant = Address::generate(env);
+    client.register_merchant(&merchant);
+
+    let token_admin = Address::generate(env);
+    let token = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    client.add_accepted_token(&client.get_admin(), &token);
+
+    let payer = Address::generate(env);
+    token::StellarAssetClient::new(env, &token).mint(&payer, &1000);
+
+    let invoice_id = client.create_invoice(
+        &merchant,
+        &InvoiceSpec {
+            description: String::from_str(env, "Goods"),
+            amount: 1000,
+            token: token.clone(),
+            expires_at: None,
+            escrow: true,
+            line_items: Vec::new(env),
+            allowed_payers: Vec::new(env),
+            reference: None,
+            splits: Vec::new(env),
+        },
+    );
+    client.pay_invoice(&payer, &invoice_id);
+
+    (merchant, payer, token, invoice_id)
+}
+
+#[test]
+fn test_escrow_payment_is_held_by_contract() {
+    let (env, client, contract_id, _admin) = setup_test();
+    let (merchant, _payer, token, invoice_id) = setup_escrowed_invoice(&env, &client);
+
+    let invoice = client.get_invoice(&invoice_id);
+    assert!(invoice.escrow);
+    assert_eq!(invoice.status, InvoiceStatus::Escrowed);
+    assert_eq!(
+        invoice.escrow_release_at,
+        Some(1_000 + DEFAULT_ESCROW_RELEASE_WINDOW)
+    );
+
+    let token_client = token::Client::new(&env, &token);
+    assert_eq!(token_client.balance(&contract_id), 1000);
+    assert_eq!(token_client.balance(&merchant), 0);
+}
+
+#[test]
+fn test_confirm_delivery_releases_to_merchant() {
+    let (env, client, contract_id, admin) = setup_test();
+    let (merchant, payer, token, invoice_id) = setup_escrowed_invoice(&env, &client);
+
+    client.set_token_fee(&admin, &token, &100, &0);
+    client.confirm_delivery(&payer, &invoice_id);
+
+    let events = env.events().all();
+    let (_event_contract_id, topics, data) = events.get(events.len() - 1).unwrap();
+    let event_name: Symbol = topics.get(0).unwrap().try_into_val(&env).unwrap();
+    assert_eq!(event_name, Symbol::new(&env, "escrow_released_event"));
+
+    let data_map: Map<Symbol, Val> = data.try_into_val(&env).unwrap();
+    let amount_in_event: i128 = data_map
+        .get(Symbol::new(&env, "amount"))
+        .unwrap()
+        .try_into_val(&env)
+        .unwrap();
+    assert_eq!(amount_in_event, 990);
+
+    assert_eq!(
+        client.get_invoice(&invoice_id).status,
+        InvoiceStatus::Released
+    );
+
+    let token_client = token::Client::new(&env, &token);
+    assert_eq!(token_client.balance(&merchant), 990);
+    assert_eq!(token_client.balance(&contract_id), 10);
+    assert_eq!(client.get_collected_fees(&token), 10);
+}
+
+#[should_panic(expected = "HostError: Error(Contract, #1)")]
+#[test]
+fn test_confirm_delivery_by_non_payer() {
+    let (env, client, _contract_id, _admin) = setup_test();
+    let (_merchant, _payer, _token, invoice_id) = setup_escrowed_invoice(&env, &client);
+
+    client.confirm_delivery(&Address::generate(&env), &invoice_id);
+}
+
+#[test]
+fn test_release_escrow_after_window() {
+    let (env, client, _contract_id, admin) = setup_test();
+
+    client.set_escrow_release_window(&admin, &3_600);
+    assert_eq!(client.get_escrow_release_window(), 3_600);
+
+    let (merchant, _payer, token, invoice_id) = setup_escrowed_invoice(&env, &client);
+
+    env.ledger().set_timestamp(1_000 + 3_599);
+    let early = client.try_release_escrow(&invoice_id);
+    assert!(early.is_err());
+
+    env.ledger().set_timestamp(1_000 + 3_600);
+    client.release_escrow(&invoice_id);
+
+    assert_eq!(
+        client.get_invoice(&invoice_id).status,
+        InvoiceStatus::Released
+    );
+    assert_eq!(token::Client::new(&env, &token).balance(&merchant), 1000);
+}
+
+#[test]
+fn test_refund_escrowed_invoice_from_custody() {
+    let (env, client, contract_id, _admin) = setup_test();
+    let (merchant, payer, token, invoice_id) = setup_escrowed_invoice(&env, &client);
+
+    client.refund_invoice(&merchant, &invoice_id, &1000);
+
+    assert_eq!(
+        client.get_invoice(&invoice_id).status,
+        InvoiceStatus::Refunded
+    );
+
+    let token_client = token::Client::new(&env, &token);
+    assert_eq!(token_client.balance(&payer), 1000);
+    assert_eq!(token_client.balance(&contract_id), 0);
+}
+
+#[should_panic(expected = "HostError: Error(Contract, #23)")]
+#[test]
+fn test_confirm_delivery_on_regular_invoice() {
+    let (env, client, _contract_id, _admin) = setup_test();
+
+    let merchant = Address::generate(&env);
+    client.register_merchant(&merchant);
+
+    let token_admin = Address::generate(&env);
+    let token = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    client.add_accepted_token(&client.get_admin(), &token);
+    let payer = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&payer, &1000);
+
+    let invoice_id = client.create_invoice(
+        &merchant,
+        &InvoiceSpec {
+            description: String::from_str(&env, "Goods"),
+            amount: 1000,
+            token: token.clone(),
+            expires_at: None,
+            escrow: false,
+            line_items: Vec::new(&env),
+            allowed_payers: Vec::new(&env),
+            reference: None,
+            splits: Vec::new(&env),
+        },
+    );
+    client.pay_invoice(&payer, &invoice_id);
+
+    client.confirm_delivery(&payer, &invoice_id);
+}
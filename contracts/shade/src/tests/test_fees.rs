@@ -0,0 +1,185 @@
+#![cfg(test)]
+
+use crate::shade::{Shade, ShadeClient};
+use crate::types::{InvoiceSpec, TokenFee};
+use soroban_sdk::testutils::{Address as _, Events as _};
+use soroban_sdk::{token, Address, Env, Map, String, Symbol, TryIntoVal, Val, Vec};
+
+fn setup_test() -> (Env, ShadeClient<'static>, Address, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(Shade, ());
+    let client = ShadeClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+    (env, client, contract_id, admin)
+}
+
+fn create_test_token(env: &Env, client: &ShadeClient) -> Address {
+    let token_admin = Address::generate(env);
+    let token = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    client.add_accepted_token(&client.get_admin(), &token);
+    token
+}
+
+fn create_paid_invoice(
+    env: &Env,
+    client: &ShadeClient,
+    token: &Address,
+    amount: i128,
+) -> (Address, Address) {
+    let merchant = Address::generate(env);
+    client.register_merchant(&merchant);
+
+    let payer = Address::generate(env);
+    token::StellarAssetClient::new(env, token).mint(&payer, &amount);
+
+    let invoice_id = client.create_invoice(
+        &merchant,
+        &InvoiceSpec {
+            description: String::from_str(env, "Invoice"),
+            amount,
+            token: token.clone(),
+            expires_at: None,
+            escrow: false,
+            line_items: Vec::new(env),
+            allowed_payers: Vec::new(env),
+            reference: None,
+            splits: Vec::new(env),
+        },
+    );
+    client.pay_invoice(&payer, &invoice_id);
+
+    (merchant, payer)
+}
+
+#[test]
+fn test_set_and_get_token_fee() {
+    let (env, client, contract_id, admin) = setup_test();
+    let token = create_test_token(&env, &client);
+
+    assert_eq!(
+        client.get_token_fee(&token),
+        TokenFee {
+            fee_in_basis_points: 0,
+            fee_amount: 0,
+        }
+    );
+
+    client.set_token_fee(&admin, &token, &250, &10);
+
+    let events = env.events().all();
+    let (event_contract_id, topics, data) = events.get(events.len() - 1).unwrap();
+    assert_eq!(event_contract_id, contract_id);
+
+    let event_name: Symbol = topics.get(0).unwrap().try_into_val(&env).unwrap();
+    assert_eq!(event_name, Symbol::new(&env, "token_fee_set_event"));
+
+    let data_map: Map<Symbol, Val> = data.try_into_val(&env).unwrap();
+    let bps_in_event: u32 = data_map
+        .get(Symbol::new(&env, "fee_in_basis_points"))
+        .unwrap()
+        .try_into_val(&env)
+        .unwrap();
+    assert_eq!(bps_in_event, 250);
+
+    assert_eq!(
+        client.get_token_fee(&token),
+        TokenFee {
+            fee_in_basis_points: 250,
+            fee_amount: 10,
+        }
+    );
+}
+
+#[test]
+fn test_fee_is_deducted_at_settlement() {
+    let (env, client, contract_id, admin) = setup_test();
+    let token = create_test_token(&env, &client);
+
+    client.set_token_fee(&admin, &token, &250, &10);
+
+    let (merchant, payer) = create_paid_invoice(&env, &client, &token, 1000);
+
+    let token_client = token::Client::new(&env, &token);
+    assert_eq!(token_client.balance(&payer), 0);
+    assert_eq!(token_client.balance(&merchant), 965);
+    assert_eq!(token_client.balance(&contract_id), 35);
+    assert_eq!(client.get_collected_fees(&token), 35);
+}
+
+#[test]
+fn test_fee_is_capped_at_invoice_amount() {
+    let (env, client, contract_id, admin) = setup_test();
+    let token = create_test_token(&env, &client);
+
+    client.set_token_fee(&admin, &token, &0, &500);
+
+    let (merchant, _payer) = create_paid_invoice(&env, &client, &token, 100);
+
+    let token_client = token::Client::new(&env, &token);
+    assert_eq!(token_client.balance(&merchant), 0);
+    assert_eq!(token_client.balance(&contract_id), 100);
+    assert_eq!(client.get_collected_fees(&token), 100);
+}
+
+#[test]
+fn test_withdraw_fees_to_treasury() {
+    let (env, client, contract_id, admin) = setup_test();
+    let token = create_test_token(&env, &client);
+
+    client.set_token_fee(&admin, &token, &1000, &0);
+    create_paid_invoice(&env, &client, &token, 1000);
+    create_paid_invoice(&env, &client, &token, 2000);
+    assert_eq!(client.get_collected_fees(&token), 300);
+
+    let treasury = Address::generate(&env);
+    client.withdraw_fees(&admin, &token, &treasury);
+
+    let events = env.events().all();
+    let (_event_contract_id, topics, data) = events.get(events.len() - 1).unwrap();
+    let event_name: Symbol = topics.get(0).unwrap().try_into_val(&env).unwrap();
+    assert_eq!(event_name, Symbol::new(&env, "fees_withdrawn_event"));
+
+    let data_map: Map<Symbol, Val> = data.try_into_val(&env).unwrap();
+    let amount_in_event: i128 = data_map
+        .get(Symbol::new(&env, "amount"))
+        .unwrap()
+        .try_into_val(&env)
+        .unwrap();
+    assert_eq!(amount_in_event, 300);
+
+    let token_client = token::Client::new(&env, &token);
+    assert_eq!(token_client.balance(&treasury), 300);
+    assert_eq!(token_client.balance(&contract_id), 0);
+    assert_eq!(client.get_collected_fees(&token), 0);
+}
+
+#[should_panic(expected = "HostError: Error(Contract, #7)")]
+#[test]
+fn test_withdraw_fees_with_nothing_collected() {
+    let (env, client, _contract_id, admin) = setup_test();
+    let token = create_test_token(&env, &client);
+
+    client.withdraw_fees(&admin, &token, &Address::generate(&env));
+}
+
+#[should_panic(expected = "HostError: Error(Contract, #13)")]
+#[test]
+fn test_set_token_fee_above_max_basis_points() {
+    let (env, client, _contract_id, admin) = setup_test();
+    let token = create_test_token(&env, &client);
+
+    client.set_token_fee(&admin, &token, &10_001, &0);
+}
+
+#[should_panic(expected = "HostError: Error(Contract, #1)")]
+#[test]
+fn test_non_admin_cannot_set_token_fee() {
+    let (env, client, _contract_id, _admin) = setup_test();
+    let token = create_test_token(&env, &client);
+
+    client.set_token_fee(&Address::generate(&env), &token, &100, &0);
+}
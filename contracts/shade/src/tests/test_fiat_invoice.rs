@@ -0,0 +1,242 @@
+#![cfg(test)]
+
+use crate::shade::{Shade, ShadeClient};
+use crate::types::{Asset, InvoiceStatus, PriceData};
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{contract, contractimpl, symbol_short, token, Address, Env, String, Symbol, Vec};
+
+#[contract]
+pub struct MockOracle;
+
+#[contractimpl]
+impl MockOracle {
+    pub fn set_price(env: Env, asset: Asset, price: i128, timestamp: u64) {
+        let mut history: Vec<PriceData> = env
+            .storage()
+            .persistent()
+            .get(&asset)
+            .unwrap_or(Vec::new(&env));
+        history.push_front(PriceData { price, timestamp });
+        env.storage().persistent().set(&asset, &history);
+    }
+
+    pub fn set_decimals(env: Env, decimals: u32) {
+        env.storage()
+            .instance()
+            .set(&symbol_short!("decimals"), &decimals);
+    }
+
+    pub fn base(env: Env) -> Asset {
+        Asset::Other(Symbol::new(&env, "USD"))
+    }
+
+    pub fn decimals(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&symbol_short!("decimals"))
+            .unwrap_or(7)
+    }
+
+    pub fn lastprice(env: Env, asset: Asset) -> Option<PriceData> {
+        Self::prices(env, asset, 1).and_then(|history| history.first())
+    }
+
+    pub fn prices(env: Env, asset: Asset, records: u32) -> Option<Vec<PriceData>> {
+        let history: Vec<PriceData> = env.storage().persistent().get(&asset)?;
+        Some(history.slice(..records.min(history.len())))
+    }
+}
+
+fn setup_test() -> (Env, ShadeClient<'static>, Address, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+    let contract_id = env.register(Shade, ());
+    let client = ShadeClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+    (env, client, contract_id, admin)
+}
+
+fn setup_oracle(
+    env: &Env,
+    client: &ShadeClient,
+    admin: &Address,
+) -> (MockOracleClient<'static>, Address) {
+    let oracle_id = env.register(MockOracle, ());
+    let oracle = MockOracleClient::new(env, &oracle_id);
+    client.set_oracle(admin, &oracle_id, &300, &200);
+
+    let token_admin = Address::generate(env);
+    let token = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    client.add_accepted_token(&client.get_admin(), &token);
+
+    // 1 token = 0.50 USD, 1 EUR = 1.10 USD
+    oracle.set_price(&Asset::Stellar(token.clone()), &5_000_000, &1_000);
+    oracle.set_price(&Asset::Other(Symbol::new(env, "EUR")), &11_000_000, &1_000);
+
+    (oracle, token)
+}
+
+fn setup_fiat_invoice(
+    env: &Env,
+    client: &ShadeClient,
+    token: &Address,
+    currency: &str,
+) -> (Address, Address, u64) {
+    setup_fiat_invoice_for(env, client, token, 1_000, currency)
+}
+
+fn setup_fiat_invoice_for(
+    env: &Env,
+    client: &ShadeClient,
+    token: &Address,
+    fiat_amount: i128,
+    currency: &str,
+) -> (Address, Address, u64) {
+    let merchant = Address::generate(env);
+    client.register_merchant(&merchant);
+
+    let payer = Address::generate(env);
+    token::StellarAssetClient::new(env, token).mint(&payer, &10_000);
+
+    let invoice_id = client.create_fiat_invoice(
+        &merchant,
+        &String::from_str(env, "Consulting"),
+        &fiat_amount,
+        &Symbol::new(env, currency),
+        token,
+        &None,
+    );
+
+    (merchant, payer, invoice_id)
+}
+
+#[test]
+fn test_usd_invoice_quoted_in_tokens() {
+    let (env, client, _contract_id, admin) = setup_test();
+    let (_oracle, token) = setup_oracle(&env, &client, &admin);
+    let (merchant, payer, invoice_id) = setup_fiat_invoice(&env, &client, &token, "USD");
+
+    let invoice = client.get_invoice(&invoice_id);
+    assert_eq!(invoice.fiat_amount, Some(1_000));
+    assert_eq!(invoice.currency, Some(Symbol::new(&env, "USD")));
+    assert_eq!(invoice.amount, 2_000);
+
+    client.pay_invoice(&payer, &invoice_id);
+    assert_eq!(client.get_invoice(&invoice_id).status, InvoiceStatus::Paid);
+    assert_eq!(token::Client::new(&env, &token).balance(&merchant), 2_000);
+}
+
+#[test]
+fn test_payment_uses_price_at_payment_time() {
+    let (env, client, _contract_id, admin) = setup_test();
+    let (oracle, token) = setup_oracle(&env, &client, &admin);
+    let (merchant, payer, invoice_id) = setup_fiat_invoice(&env, &client, &token, "EUR");
+    assert_eq!(client.get_invoice(&invoice_id).amount, 2_200);
+
+    env.ledger().set_timestamp(1_100);
+    oracle.set_price(&Asset::Stellar(token.clone()), &5_050_000, &1_100);
+    client.pay_invoice(&payer, &invoice_id);
+
+    let invoice = client.get_invoice(&invoice_id);
+    assert_eq!(invoice.amount, 2_178);
+    assert_eq!(invoice.amount_paid, 2_178);
+    assert_eq!(token::Client::new(&env, &token).balance(&merchant), 2_178);
+}
+
+#[test]
+fn test_quote_normalizes_oracle_decimals() {
+    let (env, client, _contract_id, admin) = setup_test();
+    let oracle_id = env.register(MockOracle, ());
+    let oracle = MockOracleClient::new(&env, &oracle_id);
+    oracle.set_decimals(&14);
+    client.set_oracle(&admin, &oracle_id, &300, &200);
+
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    client.add_accepted_token(&admin, &token);
+    oracle.set_price(&Asset::Stellar(token.clone()), &50_000_000_000_000, &1_000);
+
+    // 10 USD at the oracle's 14 decimals, 1 token = 0.50 USD, token has 7 decimals
+    let (_merchant, _payer, invoice_id) =
+        setup_fiat_invoice_for(&env, &client, &token, 1_000_000_000_000_000, "USD");
+    assert_eq!(client.get_invoice(&invoice_id).amount, 200_000_000);
+}
+
+#[test]
+fn test_gradual_drift_within_average_accepted() {
+    let (env, client, _contract_id, admin) = setup_test();
+    let (oracle, token) = setup_oracle(&env, &client, &admin);
+    let (_merchant, payer, invoice_id) = setup_fiat_invoice(&env, &client, &token, "USD");
+
+    // Each sample moves 1%, so the spot price stays within 2% of the recent
+    // average even though it ends far from the creation quote.
+    let mut price = 5_000_000;
+    for step in 1..=12u64 {
+        price = price * 101 / 100;
+        env.ledger().set_timestamp(1_000 + step * 100);
+        oracle.set_price(
+            &Asset::Stellar(token.clone()),
+            &price,
+            &(1_000 + step * 100),
+        );
+    }
+
+    client.pay_invoice(&payer, &invoice_id);
+    let invoice = client.get_invoice(&invoice_id);
+    assert_eq!(invoice.status, InvoiceStatus::Paid);
+    assert!(invoice.amount < 2_000 * 100 / 110);
+}
+
+#[should_panic(expected = "HostError: Error(Contract, #40)")]
+#[test]
+fn test_stale_price_rejected() {
+    let (env, client, _contract_id, admin) = setup_test();
+    let (_oracle, token) = setup_oracle(&env, &client, &admin);
+    let (_merchant, payer, invoice_id) = setup_fiat_invoice(&env, &client, &token, "USD");
+
+    env.ledger().set_timestamp(1_000 + 301);
+    client.pay_invoice(&payer, &invoice_id);
+}
+
+#[should_panic(expected = "HostError: Error(Contract, #41)")]
+#[test]
+fn test_price_deviation_rejected() {
+    let (env, client, _contract_id, admin) = setup_test();
+    let (oracle, token) = setup_oracle(&env, &client, &admin);
+    let (_merchant, payer, invoice_id) = setup_fiat_invoice(&env, &client, &token, "USD");
+
+    oracle.set_price(&Asset::Stellar(token.clone()), &4_000_000, &1_000);
+    client.pay_invoice(&payer, &invoice_id);
+}
+
+#[should_panic(expected = "HostError: Error(Contract, #39)")]
+#[test]
+fn test_unknown_currency_rejected() {
+    let (env, client, _contract_id, admin) = setup_test();
+    let (_oracle, token) = setup_oracle(&env, &client, &admin);
+    setup_fiat_invoice(&env, &client, &token, "GBP");
+}
+
+#[should_panic(expected = "HostError: Error(Contract, #37)")]
+#[test]
+fn test_fiat_invoice_without_oracle() {
+    let (env, client, _contract_id, _admin) = setup_test();
+    let token_admin = Address::generate(&env);
+    let token = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    client.add_accepted_token(&client.get_admin(), &token);
+    setup_fiat_invoice(&env, &client, &token, "USD");
+}
+
+#[should_panic(expected = "HostError: Error(Contract, #38)")]
+#[test]
+fn test_set_oracle_invalid_deviation() {
+    let (env, client, _contract_id, admin) = setup_test();
+    client.set_oracle(&admin, &Address::generate(&env), &300, &10_001);
+}
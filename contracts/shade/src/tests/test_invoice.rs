@@ -1,9 +1,9 @@
 #![cfg(test)]
 
 use crate::shade::{Shade, ShadeClient};
-use crate::types::InvoiceStatus;
+use crate::types::{InvoiceSpec, InvoiceStatus, LineItem};
 use soroban_sdk::testutils::{Address as _, Events as _};
-use soroban_sdk::{Address, Env, Map, String, Symbol, TryIntoVal, Val};
+use soroban_sdk::{Address, Env, Map, String, Symbol, TryIntoVal, Val, Vec};
 
 fn setup_test() -> (Env, ShadeClient<'static>, Address, Address) {
     let env = Env::default();
@@ -15,6 +15,15 @@ fn setup_test() -> (Env, ShadeClient<'static>, Address, Address) {
     (env, client, contract_id, admin)
 }
 
+fn create_test_token(env: &Env, client: &ShadeClient) -> Address {
+    let token_admin = Address::generate(env);
+    let token = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    client.add_accepted_token(&client.get_admin(), &token);
+    token
+}
+
 fn assert_latest_invoice_event(
     env: &Env,
     contract_id: &Address,
@@ -24,7 +33,7 @@ fn assert_latest_invoice_event(
     expected_token: &Address,
 ) {
     let events = env.events().all();
-    assert!(events.len() > 0, "No events captured for invoice!");
+    assert!(!events.is_empty(), "No events captured for invoice!");
 
     let (event_contract_id, _topics, data) = events.get(events.len() - 1).unwrap();
     assert_eq!(&event_contract_id, contract_id);
@@ -54,11 +63,24 @@ fn test_create_and_get_invoice_success() {
     let merchant = Address::generate(&env);
     client.register_merchant(&merchant);
 
-    let token = Address::generate(&env);
+    let token = create_test_token(&env, &client);
     let description = String::from_str(&env, "Test Invoice");
     let amount: i128 = 1000;
 
-    let invoice_id = client.create_invoice(&merchant, &description, &amount, &token);
+    let invoice_id = client.create_invoice(
+        &merchant,
+        &InvoiceSpec {
+            description: description.clone(),
+            amount,
+            token: token.clone(),
+            expires_at: None,
+            escrow: false,
+            line_items: Vec::new(&env),
+            allowed_payers: Vec::new(&env),
+            reference: None,
+            splits: Vec::new(&env),
+        },
+    );
     assert_eq!(invoice_id, 1);
 
     assert_latest_invoice_event(&env, &contract_id, invoice_id, &merchant, amount, &token);
@@ -80,26 +102,50 @@ fn test_create_multiple_invoices() {
     let merchant = Address::generate(&env);
     client.register_merchant(&merchant);
 
-    let token1 = Address::generate(&env);
-    let token2 = Address::generate(&env);
+    let token1 = create_test_token(&env, &client);
+    let token2 = create_test_token(&env, &client);
 
     let id1 = client.create_invoice(
         &merchant,
-        &String::from_str(&env, "Invoice 1"),
-        &1000,
-        &token1,
+        &InvoiceSpec {
+            description: String::from_str(&env, "Invoice 1"),
+            amount: 1000,
+            token: token1.clone(),
+            expires_at: None,
+            escrow: false,
+            line_items: Vec::new(&env),
+            allowed_payers: Vec::new(&env),
+            reference: None,
+            splits: Vec::new(&env),
+        },
     );
     let id2 = client.create_invoice(
         &merchant,
-        &String::from_str(&env, "Invoice 2"),
-        &2000,
-        &token2,
+        &InvoiceSpec {
+            description: String::from_str(&env, "Invoice 2"),
+            amount: 2000,
+            token: token2.clone(),
+            expires_at: None,
+            escrow: false,
+            line_items: Vec::new(&env),
+            allowed_payers: Vec::new(&env),
+            reference: None,
+            splits: Vec::new(&env),
+        },
     );
     let id3 = client.create_invoice(
         &merchant,
-        &String::from_str(&env, "Invoice 3"),
-        &500,
-        &token1,
+        &InvoiceSpec {
+            description: String::from_str(&env, "Invoice 3"),
+            amount: 500,
+            token: token1.clone(),
+            expires_at: None,
+            escrow: false,
+            line_items: Vec::new(&env),
+            allowed_payers: Vec::new(&env),
+            reference: None,
+            splits: Vec::new(&env),
+        },
     );
 
     assert_eq!(id1, 1);
@@ -120,11 +166,24 @@ fn test_create_invoice_unregistered_merchant() {
     let (env, client, _contract_id, _admin) = setup_test();
 
     let unregistered_merchant = Address::generate(&env);
-    let token = Address::generate(&env);
+    let token = create_test_token(&env, &client);
     let description = String::from_str(&env, "Test Invoice");
     let amount: i128 = 1000;
 
-    client.create_invoice(&unregistered_merchant, &description, &amount, &token);
+    client.create_invoice(
+        &unregistered_merchant,
+        &InvoiceSpec {
+            description: description.clone(),
+            amount,
+            token: token.clone(),
+            expires_at: None,
+            escrow: false,
+            line_items: Vec::new(&env),
+            allowed_payers: Vec::new(&env),
+            reference: None,
+            splits: Vec::new(&env),
+        },
+    );
 }
 
 #[should_panic(expected = "HostError: Error(Contract, #7)")]
@@ -135,9 +194,192 @@ fn test_create_invoice_invalid_amount() {
     let merchant = Address::generate(&env);
     client.register_merchant(&merchant);
 
-    let token = Address::generate(&env);
+    let token = create_test_token(&env, &client);
     let description = String::from_str(&env, "Test Invoice");
     let amount: i128 = 0;
 
-    client.create_invoice(&merchant, &description, &amount, &token);
+    client.create_invoice(
+        &merchant,
+        &InvoiceSpec {
+            description: description.clone(),
+            amount,
+            token: token.clone(),
+            expires_at: None,
+            escrow: false,
+            line_items: Vec::new(&env),
+            allowed_payers: Vec::new(&env),
+            reference: None,
+            splits: Vec::new(&env),
+        },
+    );
+}
+
+fn line_item(
+    env: &Env,
+    label: &str,
+    quantity: u32,
+    unit_price: i128,
+    tax: u32,
+    discount: i128,
+) -> LineItem {
+    LineItem {
+        label: String::from_str(env, label),
+        quantity,
+        unit_price,
+        tax_basis_points: tax,
+        discount,
+    }
+}
+
+#[test]
+fn test_create_invoice_with_line_items() {
+    let (env, client, _contract_id, _admin) = setup_test();
+
+    let merchant = Address::generate(&env);
+    client.register_merchant(&merchant);
+    let token = create_test_token(&env, &client);
+
+    let mut line_items = Vec::new(&env);
+    line_items.push_back(line_item(&env, "Widget", 3, 200, 1_000, 100));
+    line_items.push_back(line_item(&env, "Shipping", 1, 50, 0, 0));
+
+    let invoice_id = client.create_invoice(
+        &merchant,
+        &InvoiceSpec {
+            description: String::from_str(&env, "Order #42"),
+            amount: 600,
+            token,
+            expires_at: None,
+            escrow: false,
+            line_items: line_items.clone(),
+            allowed_payers: Vec::new(&env),
+            reference: None,
+            splits: Vec::new(&env),
+        },
+    );
+
+    let invoice = client.get_invoice(&invoice_id);
+    assert_eq!(invoice.amount, 600);
+    assert_eq!(invoice.subtotal, 650);
+    assert_eq!(invoice.discount_amount, 100);
+    assert_eq!(invoice.tax_amount, 50);
+    assert_eq!(invoice.line_items, line_items);
+}
+
+#[should_panic(expected = "HostError: Error(Contract, #43)")]
+#[test]
+fn test_create_invoice_line_item_total_mismatch() {
+    let (env, client, _contract_id, _admin) = setup_test();
+
+    let merchant = Address::generate(&env);
+    client.register_merchant(&merchant);
+
+    let mut line_items = Vec::new(&env);
+    line_items.push_back(line_item(&env, "Widget", 2, 250, 0, 0));
+
+    client.create_invoice(
+        &merchant,
+        &InvoiceSpec {
+            description: String::from_str(&env, "Order"),
+            amount: 400,
+            token: create_test_token(&env, &client),
+            expires_at: None,
+            escrow: false,
+            line_items,
+            allowed_payers: Vec::new(&env),
+            reference: None,
+            splits: Vec::new(&env),
+        },
+    );
+}
+
+#[should_panic(expected = "HostError: Error(Contract, #42)")]
+#[test]
+fn test_create_invoice_discount_exceeds_line() {
+    let (env, client, _contract_id, _admin) = setup_test();
+
+    let merchant = Address::generate(&env);
+    client.register_merchant(&merchant);
+
+    let mut line_items = Vec::new(&env);
+    line_items.push_back(line_item(&env, "Widget", 1, 100, 0, 150));
+
+    client.create_invoice(
+        &merchant,
+        &InvoiceSpec {
+            description: String::from_str(&env, "Order"),
+            amount: 100,
+            token: create_test_token(&env, &client),
+            expires_at: None,
+            escrow: false,
+            line_items,
+            allowed_payers: Vec::new(&env),
+            reference: None,
+            splits: Vec::new(&env),
+        },
+    );
+}
+
+fn referenced_spec(env: &Env, token: &Address, reference: &str) -> InvoiceSpec {
+    InvoiceSpec {
+        description: String::from_str(env, "Order"),
+        amount: 1000,
+        token: token.clone(),
+        expires_at: None,
+        escrow: false,
+        line_items: Vec::new(env),
+        allowed_payers: Vec::new(env),
+        reference: Some(String::from_str(env, reference)),
+        splits: Vec::new(env),
+    }
+}
+
+#[test]
+fn test_get_invoice_by_reference() {
+    let (env, client, _contract_id, _admin) = setup_test();
+
+    let merchant = Address::generate(&env);
+    let other_merchant = Address::generate(&env);
+    client.register_merchant(&merchant);
+    client.register_merchant(&other_merchant);
+    let token = create_test_token(&env, &client);
+
+    let invoice_id = client.create_invoice(&merchant, &referenced_spec(&env, &token, "ORD-1001"));
+    let other_id =
+        client.create_invoice(&other_merchant, &referenced_spec(&env, &token, "ORD-1001"));
+
+    let reference = String::from_str(&env, "ORD-1001");
+    let invoice = client.get_invoice_by_reference(&merchant, &reference);
+    assert_eq!(invoice.id, invoice_id);
+    assert_eq!(invoice.reference, Some(reference.clone()));
+    assert_eq!(
+        client
+            .get_invoice_by_reference(&other_merchant, &reference)
+            .id,
+        other_id
+    );
+}
+
+#[should_panic(expected = "HostError: Error(Contract, #46)")]
+#[test]
+fn test_duplicate_reference_rejected() {
+    let (env, client, _contract_id, _admin) = setup_test();
+
+    let merchant = Address::generate(&env);
+    client.register_merchant(&merchant);
+    let token = create_test_token(&env, &client);
+
+    client.create_invoice(&merchant, &referenced_spec(&env, &token, "ORD-1001"));
+    client.create_invoice(&merchant, &referenced_spec(&env, &token, "ORD-1001"));
+}
+
+#[should_panic(expected = "HostError: Error(Contract, #8)")]
+#[test]
+fn test_get_invoice_by_unknown_reference() {
+    let (env, client, _contract_id, _admin) = setup_test();
+
+    let merchant = Address::generate(&env);
+    client.register_merchant(&merchant);
+
+    client.get_invoice_by_reference(&merchant, &String::from_str(&env, "ORD-404"));
 }
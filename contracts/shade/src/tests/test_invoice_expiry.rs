@@ -0,0 +1,212 @@
+#![cfg(test)]
+
+use crate::shade::{Shade, ShadeClient};
+use crate::types::{CancellationReason, InvoiceFilter, InvoiceSpec, InvoiceStatus};
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{token, Address, Env, String, Vec};
+
+fn setup_test() -> (Env, ShadeClient<'static>, Address, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+    let contract_id = env.register(Shade, ());
+    let client = ShadeClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+    (env, client, contract_id, admin)
+}
+
+fn setup_merchant_and_token(env: &Env, client: &ShadeClient) -> (Address, Address) {
+    let merchant = Address::generate(env);
+    client.register_merchant(&merchant);
+
+    let token_admin = Address::generate(env);
+    let token = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    client.add_accepted_token(&client.get_admin(), &token);
+
+    (merchant, token)
+}
+
+fn status_filter(status: InvoiceStatus) -> InvoiceFilter {
+    InvoiceFilter {
+        status: Some(status as u32),
+        merchant: None,
+        min_amount: None,
+        max_amount: None,
+        payer: None,
+    }
+}
+
+#[test]
+fn test_pay_invoice_before_expiry() {
+    let (env, client, _contract_id, _admin) = setup_test();
+    let (merchant, token) = setup_merchant_and_token(&env, &client);
+
+    let invoice_id = client.create_invoice(
+        &merchant,
+        &InvoiceSpec {
+            description: String::from_str(&env, "Checkout"),
+            amount: 1000,
+            token: token.clone(),
+            expires_at: Some(2_000),
+            escrow: false,
+            line_items: Vec::new(&env),
+            allowed_payers: Vec::new(&env),
+            reference: None,
+            splits: Vec::new(&env),
+        },
+    );
+    assert_eq!(client.get_invoice(&invoice_id).expires_at, Some(2_000));
+
+    let payer = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&payer, &1000);
+
+    env.ledger().set_timestamp(1_999);
+    client.pay_invoice(&payer, &invoice_id);
+
+    env.ledger().set_timestamp(5_000);
+    assert_eq!(client.get_invoice(&invoice_id).status, InvoiceStatus::Paid);
+}
+
+#[should_panic(expected = "HostError: Error(Contract, #21)")]
+#[test]
+fn test_pay_invoice_after_expiry() {
+    let (env, client, _contract_id, _admin) = setup_test();
+    let (merchant, token) = setup_merchant_and_token(&env, &client);
+
+    let invoice_id = client.create_invoice(
+        &merchant,
+        &InvoiceSpec {
+            description: String::from_str(&env, "Checkout"),
+            amount: 1000,
+            token: token.clone(),
+            expires_at: Some(2_000),
+            escrow: false,
+            line_items: Vec::new(&env),
+            allowed_payers: Vec::new(&env),
+            reference: None,
+            splits: Vec::new(&env),
+        },
+    );
+
+    let payer = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&payer, &1000);
+
+    env.ledger().set_timestamp(2_000);
+    client.pay_invoice(&payer, &invoice_id);
+}
+
+#[should_panic(expected = "HostError: Error(Contract, #20)")]
+#[test]
+fn test_create_invoice_with_past_expiry() {
+    let (env, client, _contract_id, _admin) = setup_test();
+    let (merchant, token) = setup_merchant_and_token(&env, &client);
+
+    client.create_invoice(
+        &merchant,
+        &InvoiceSpec {
+            description: String::from_str(&env, "Checkout"),
+            amount: 1000,
+            token: token.clone(),
+            expires_at: Some(1_000),
+            escrow: false,
+            line_items: Vec::new(&env),
+            allowed_payers: Vec::new(&env),
+            reference: None,
+            splits: Vec::new(&env),
+        },
+    );
+}
+
+#[test]
+fn test_filter_expired_and_live_invoices() {
+    let (env, client, _contract_id, _admin) = setup_test();
+    let (merchant, token) = setup_merchant_and_token(&env, &client);
+
+    let short_id = client.create_invoice(
+        &merchant,
+        &InvoiceSpec {
+            description: String::from_str(&env, "Short"),
+            amount: 1000,
+            token: token.clone(),
+            expires_at: Some(1_500),
+            escrow: false,
+            line_items: Vec::new(&env),
+            allowed_payers: Vec::new(&env),
+            reference: None,
+            splits: Vec::new(&env),
+        },
+    );
+    let long_id = client.create_invoice(
+        &merchant,
+        &InvoiceSpec {
+            description: String::from_str(&env, "Long"),
+            amount: 1000,
+            token: token.clone(),
+            expires_at: Some(10_000),
+            escrow: false,
+            line_items: Vec::new(&env),
+            allowed_payers: Vec::new(&env),
+            reference: None,
+            splits: Vec::new(&env),
+        },
+    );
+    let open_id = client.create_invoice(
+        &merchant,
+        &InvoiceSpec {
+            description: String::from_str(&env, "Open"),
+            amount: 1000,
+            token: token.clone(),
+            expires_at: None,
+            escrow: false,
+            line_items: Vec::new(&env),
+            allowed_payers: Vec::new(&env),
+            reference: None,
+            splits: Vec::new(&env),
+        },
+    );
+
+    env.ledger().set_timestamp(2_000);
+
+    assert_eq!(client.get_invoice(&short_id).status, InvoiceStatus::Expired);
+
+    let expired = client.get_invoices(&status_filter(InvoiceStatus::Expired));
+    assert_eq!(expired.len(), 1);
+    assert_eq!(expired.get(0).unwrap().id, short_id);
+
+    let live = client.get_invoices(&status_filter(InvoiceStatus::Pending));
+    assert_eq!(live.len(), 2);
+    assert_eq!(live.get(0).unwrap().id, long_id);
+    assert_eq!(live.get(1).unwrap().id, open_id);
+}
+
+#[test]
+fn test_cancel_expired_invoice() {
+    let (env, client, _contract_id, _admin) = setup_test();
+    let (merchant, token) = setup_merchant_and_token(&env, &client);
+
+    let invoice_id = client.create_invoice(
+        &merchant,
+        &InvoiceSpec {
+            description: String::from_str(&env, "Checkout"),
+            amount: 1000,
+            token: token.clone(),
+            expires_at: Some(1_500),
+            escrow: false,
+            line_items: Vec::new(&env),
+            allowed_payers: Vec::new(&env),
+            reference: None,
+            splits: Vec::new(&env),
+        },
+    );
+
+    env.ledger().set_timestamp(2_000);
+    client.cancel_invoice(&merchant, &invoice_id, &CancellationReason::Other);
+
+    assert_eq!(
+        client.get_invoice(&invoice_id).status,
+        InvoiceStatus::Cancelled
+    );
+}
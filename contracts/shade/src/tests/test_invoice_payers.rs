@@ -0,0 +1,158 @@
+#![cfg(test)]
+
+use crate::shade::{Shade, ShadeClient};
+use crate::types::{InvoiceFilter, InvoiceSpec, InvoiceStatus};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{token, vec, Address, Env, String, Vec};
+
+fn setup_test() -> (Env, ShadeClient<'static>, Address, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(Shade, ());
+    let client = ShadeClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+    (env, client, contract_id, admin)
+}
+
+fn setup_merchant(env: &Env, client: &ShadeClient) -> (Address, Address) {
+    let merchant = Address::generate(env);
+    client.register_merchant(&merchant);
+
+    let token_admin = Address::generate(env);
+    let token = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    client.add_accepted_token(&client.get_admin(), &token);
+
+    (merchant, token)
+}
+
+fn create_addressed_invoice(
+    env: &Env,
+    client: &ShadeClient,
+    merchant: &Address,
+    token: &Address,
+    allowed_payers: Vec<Address>,
+) -> u64 {
+    client.create_invoice(
+        merchant,
+        &InvoiceSpec {
+            description: String::from_str(env, "Consulting retainer"),
+            amount: 1000,
+            token: token.clone(),
+            expires_at: None,
+            escrow: false,
+            line_items: Vec::new(env),
+            allowed_payers,
+            reference: None,
+            splits: Vec::new(env),
+        },
+    )
+}
+
+fn payer_filter(payer: &Address) -> InvoiceFilter {
+    InvoiceFilter {
+        status: None,
+        merchant: None,
+        min_amount: None,
+        max_amount: None,
+        payer: Some(payer.clone()),
+    }
+}
+
+#[test]
+fn test_designated_payer_can_settle() {
+    let (env, client, _contract_id, _admin) = setup_test();
+    let (merchant, token) = setup_merchant(&env, &client);
+
+    let debtor = Address::generate(&env);
+    let accounts_payable = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&accounts_payable, &1000);
+
+    let invoice_id = create_addressed_invoice(
+        &env,
+        &client,
+        &merchant,
+        &token,
+        vec![&env, debtor.clone(), accounts_payable.clone()],
+    );
+    assert_eq!(
+        client.get_invoice(&invoice_id).allowed_payers,
+        vec![&env, debtor, accounts_payable.clone()]
+    );
+
+    client.pay_invoice(&accounts_payable, &invoice_id);
+
+    let invoice = client.get_invoice(&invoice_id);
+    assert_eq!(invoice.status, InvoiceStatus::Paid);
+    assert_eq!(invoice.payer, Some(accounts_payable));
+}
+
+#[should_panic(expected = "HostError: Error(Contract, #44)")]
+#[test]
+fn test_other_payer_rejected() {
+    let (env, client, _contract_id, _admin) = setup_test();
+    let (merchant, token) = setup_merchant(&env, &client);
+
+    let debtor = Address::generate(&env);
+    let stranger = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&stranger, &1000);
+
+    let invoice_id = create_addressed_invoice(&env, &client, &merchant, &token, vec![&env, debtor]);
+    client.pay_invoice(&stranger, &invoice_id);
+}
+
+#[test]
+fn test_filter_invoices_addressed_to_payer() {
+    let (env, client, _contract_id, _admin) = setup_test();
+    let (merchant, token) = setup_merchant(&env, &client);
+
+    let debtor = Address::generate(&env);
+    let other_debtor = Address::generate(&env);
+    let walk_in = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&walk_in, &1000);
+
+    let addressed_id =
+        create_addressed_invoice(&env, &client, &merchant, &token, vec![&env, debtor.clone()]);
+    create_addressed_invoice(&env, &client, &merchant, &token, vec![&env, other_debtor]);
+    let open_id = create_addressed_invoice(&env, &client, &merchant, &token, Vec::new(&env));
+    client.pay_invoice(&walk_in, &open_id);
+
+    let invoices = client.get_invoices(&payer_filter(&debtor));
+    assert_eq!(invoices.len(), 1);
+    assert_eq!(invoices.get(0).unwrap().id, addressed_id);
+
+    let invoices = client.get_invoices(&payer_filter(&walk_in));
+    assert_eq!(invoices.len(), 1);
+    assert_eq!(invoices.get(0).unwrap().id, open_id);
+}
+
+#[should_panic(expected = "HostError: Error(Contract, #53)")]
+#[test]
+fn test_too_many_payers_rejected() {
+    let (env, client, _contract_id, _admin) = setup_test();
+    let (merchant, token) = setup_merchant(&env, &client);
+
+    let mut allowed_payers = Vec::new(&env);
+    for _ in 0..11 {
+        allowed_payers.push_back(Address::generate(&env));
+    }
+    create_addressed_invoice(&env, &client, &merchant, &token, allowed_payers);
+}
+
+#[should_panic(expected = "HostError: Error(Contract, #54)")]
+#[test]
+fn test_duplicate_payer_rejected() {
+    let (env, client, _contract_id, _admin) = setup_test();
+    let (merchant, token) = setup_merchant(&env, &client);
+
+    let debtor = Address::generate(&env);
+    create_addressed_invoice(
+        &env,
+        &client,
+        &merchant,
+        &token,
+        vec![&env, debtor.clone(), Address::generate(&env), debtor],
+    );
+}
@@ -0,0 +1,348 @@
+#![cfg(test)]
+
+use crate::shade::{Shade, ShadeClient};
+use crate::types::{InvoiceSpec, Role};
+use account::account::MerchantAccountClient;
+use soroban_sdk::testutils::{Address as _, Events as _};
+use soroban_sdk::{token, vec, Address, BytesN, Env, Map, String, Symbol, TryIntoVal, Val, Vec};
+
+const MERCHANT_ACCOUNT_WASM: &[u8] = include_bytes!("fixtures/merchant_account.wasm");
+
+fn setup_test() -> (Env, ShadeClient<'static>, Address, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(Shade, ());
+    let client = ShadeClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+    (env, client, contract_id, admin)
+}
+
+fn upload_account_wasm(env: &Env, client: &ShadeClient, admin: &Address) -> BytesN<32> {
+    let wasm_hash = env.deployer().upload_contract_wasm(MERCHANT_ACCOUNT_WASM);
+    client.set_account_wasm_hash(admin, &wasm_hash);
+    wasm_hash
+}
+
+#[test]
+fn test_register_merchant_deploys_account() {
+    let (env, client, _contract_id, admin) = setup_test();
+    upload_account_wasm(&env, &client, &admin);
+
+    let merchant = Address::generate(&env);
+    client.register_merchant(&merchant);
+
+    let events = env.events().all();
+    let (_event_contract_id, topics, data) = events.get(events.len() - 2).unwrap();
+    let event_name: Symbol = topics.get(0).unwrap().try_into_val(&env).unwrap();
+    assert_eq!(
+        event_name,
+        Symbol::new(&env, "merchant_account_deployed_event")
+    );
+    let data_map: Map<Symbol, Val> = data.try_into_val(&env).unwrap();
+    let account_in_event: Address = data_map
+        .get(Symbol::new(&env, "account"))
+        .unwrap()
+        .try_into_val(&env)
+        .unwrap();
+
+    let account = client.get_merchant(&1).account.unwrap();
+    assert_eq!(account_in_event, account);
+
+    let account_client = MerchantAccountClient::new(&env, &account);
+    assert_eq!(account_client.get_merchant(), merchant);
+}
+
+fn create_paid_invoice(
+    env: &Env,
+    client: &ShadeClient,
+    merchant: &Address,
+    escrow: bool,
+) -> (Address, Address, u64) {
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    client.add_accepted_token(&client.get_admin(), &token);
+
+    let invoice_id = client.create_invoice(
+        merchant,
+        &InvoiceSpec {
+            description: String::from_str(env, "Order"),
+            amount: 1000,
+            token: token.clone(),
+            expires_at: None,
+            escrow,
+            line_items: Vec::new(env),
+            allowed_payers: Vec::new(env),
+            reference: None,
+            splits: Vec::new(env),
+        },
+    );
+
+    let payer = Address::generate(env);
+    token::StellarAssetClient::new(env, &token).mint(&payer, &1000);
+    client.pay_invoice(&payer, &invoice_id);
+
+    (token, payer, invoice_id)
+}
+
+#[test]
+fn test_payment_settles_into_merchant_account() {
+    let (env, client, _contract_id, admin) = setup_test();
+    upload_account_wasm(&env, &client, &admin);
+
+    let merchant = Address::generate(&env);
+    client.register_merchant(&merchant);
+    let account = client.get_merchant(&1).account.unwrap();
+
+    let (token, _payer, _invoice_id) = create_paid_invoice(&env, &client, &merchant, false);
+
+    let token_client = token::Client::new(&env, &token);
+    assert_eq!(token_client.balance(&account), 1000);
+    assert_eq!(token_client.balance(&merchant), 0);
+
+    let account_client = MerchantAccountClient::new(&env, &account);
+    assert!(account_client.has_token(&token));
+    let balances = account_client.get_balances();
+    assert_eq!(balances.len(), 1);
+    assert_eq!(balances.get(0).unwrap().balance, 1000);
+}
+
+#[test]
+fn test_escrow_release_settles_into_merchant_account() {
+    let (env, client, _contract_id, admin) = setup_test();
+    upload_account_wasm(&env, &client, &admin);
+
+    let merchant = Address::generate(&env);
+    client.register_merchant(&merchant);
+    let account = client.get_merchant(&1).account.unwrap();
+
+    let (token, payer, invoice_id) = create_paid_invoice(&env, &client, &merchant, true);
+    client.confirm_delivery(&payer, &invoice_id);
+
+    assert_eq!(token::Client::new(&env, &token).balance(&account), 1000);
+    assert!(MerchantAccountClient::new(&env, &account).has_token(&token));
+}
+
+#[test]
+fn test_refund_drawn_from_merchant_account() {
+    let (env, client, _contract_id, admin) = setup_test();
+    upload_account_wasm(&env, &client, &admin);
+
+    let merchant = Address::generate(&env);
+    client.register_merchant(&merchant);
+    let account = client.get_merchant(&1).account.unwrap();
+    let (token, payer, invoice_id) = create_paid_invoice(&env, &client, &merchant, false);
+
+    client.refund_invoice(&merchant, &invoice_id, &400);
+
+    let manager = Address::generate(&env);
+    client.grant_role(&admin, &manager, &Role::Manager);
+    client.refund_invoice(&manager, &invoice_id, &600);
+
+    let token_client = token::Client::new(&env, &token);
+    assert_eq!(token_client.balance(&payer), 1000);
+    assert_eq!(token_client.balance(&account), 0);
+}
+
+#[should_panic(expected = "HostError: Error(Contract, #19)")]
+#[test]
+fn test_refund_exceeding_merchant_account_balance() {
+    let (env, client, _contract_id, admin) = setup_test();
+    upload_account_wasm(&env, &client, &admin);
+
+    let merchant = Address::generate(&env);
+    client.register_merchant(&merchant);
+    let account = client.get_merchant(&1).account.unwrap();
+    let (token, _payer, invoice_id) = create_paid_invoice(&env, &client, &merchant, false);
+    client.verify_merchant(&admin, &1, &true);
+    MerchantAccountClient::new(&env, &account).withdraw(&token, &800, &merchant);
+
+    // The merchant's own wallet holds the funds, but refunds only draw
+    // from the linked account.
+    client.refund_invoice(&merchant, &invoice_id, &500);
+}
+
+#[test]
+fn test_payment_falls_back_to_merchant_address() {
+    let (env, client, _contract_id, _admin) = setup_test();
+
+    let merchant = Address::generate(&env);
+    client.register_merchant(&merchant);
+
+    let (token, _payer, _invoice_id) = create_paid_invoice(&env, &client, &merchant, false);
+    assert_eq!(token::Client::new(&env, &token).balance(&merchant), 1000);
+}
+
+#[test]
+fn test_admin_manages_account_withdrawal_policy() {
+    let (env, client, _contract_id, admin) = setup_test();
+    upload_account_wasm(&env, &client, &admin);
+
+    let merchant = Address::generate(&env);
+    client.register_merchant(&merchant);
+    let account = client.get_merchant(&1).account.unwrap();
+    let (token, _payer, _invoice_id) = create_paid_invoice(&env, &client, &merchant, false);
+
+    let account_client = MerchantAccountClient::new(&env, &account);
+    assert!(account_client
+        .try_withdraw(&token, &100, &merchant)
+        .is_err());
+
+    client.verify_merchant(&admin, &1, &true);
+    assert!(account_client.is_verified_account());
+
+    client.set_merchant_payout_allowlist(&admin, &1, &vec![&env, merchant.clone()]);
+    client.set_merchant_withdrawal_limit(&admin, &1, &token, &Some(600));
+    assert_eq!(account_client.get_withdrawal_limit(&token), Some(600));
+
+    account_client.withdraw(&token, &600, &merchant);
+    assert_eq!(token::Client::new(&env, &token).balance(&merchant), 600);
+    assert!(account_client.try_withdraw(&token, &1, &merchant).is_err());
+}
+
+#[test]
+fn test_unverify_merchant_blocks_account_withdrawals() {
+    let (env, client, _contract_id, admin) = setup_test();
+    upload_account_wasm(&env, &client, &admin);
+
+    let merchant = Address::generate(&env);
+    client.register_merchant(&merchant);
+    let account = client.get_merchant(&1).account.unwrap();
+    let (token, _payer, _invoice_id) = create_paid_invoice(&env, &client, &merchant, false);
+
+    let account_client = MerchantAccountClient::new(&env, &account);
+    client.verify_merchant(&admin, &1, &true);
+    assert!(account_client.is_verified_account());
+    account_client.withdraw(&token, &100, &merchant);
+
+    client.verify_merchant(&admin, &1, &false);
+    assert!(!account_client.is_verified_account());
+    assert!(account_client
+        .try_withdraw(&token, &100, &merchant)
+        .is_err());
+}
+
+#[test]
+fn test_operator_vetoes_queued_withdrawal() {
+    let (env, client, _contract_id, admin) = setup_test();
+    upload_account_wasm(&env, &client, &admin);
+
+    let merchant = Address::generate(&env);
+    client.register_merchant(&merchant);
+    let account = client.get_merchant(&1).account.unwrap();
+    let (token, _payer, _invoice_id) = create_paid_invoice(&env, &client, &merchant, false);
+    client.verify_merchant(&admin, &1, &true);
+    client.set_merchant_withdrawal_delay(&admin, &1, &3_600);
+
+    let operator = Address::generate(&env);
+    client.grant_role(&admin, &operator, &Role::Operator);
+
+    let account_client = MerchantAccountClient::new(&env, &account);
+    let withdrawal_id = account_client.queue_withdrawal(&token, &500, &merchant);
+    client.veto_merchant_withdrawal(&operator, &1, &withdrawal_id);
+
+    assert!(account_client.get_pending_withdrawals().is_empty());
+    assert_eq!(account_client.get_balance(&token), 1000);
+}
+
+#[should_panic(expected = "HostError: Error(Contract, #52)")]
+#[test]
+fn test_withdrawal_policy_without_account() {
+    let (env, client, _contract_id, admin) = setup_test();
+
+    client.register_merchant(&Address::generate(&env));
+    client.set_merchant_withdrawal_limit(&admin, &1, &Address::generate(&env), &Some(100));
+}
+
+#[test]
+fn test_each_merchant_gets_own_account() {
+    let (env, client, _contract_id, admin) = setup_test();
+    upload_account_wasm(&env, &client, &admin);
+
+    client.register_merchant(&Address::generate(&env));
+    client.register_merchant(&Address::generate(&env));
+
+    let first = client.get_merchant(&1).account.unwrap();
+    let second = client.get_merchant(&2).account.unwrap();
+    assert_ne!(first, second);
+}
+
+#[test]
+fn test_register_merchant_without_wasm_hash() {
+    let (env, client, _contract_id, _admin) = setup_test();
+    assert_eq!(client.get_account_wasm_hash(), None);
+
+    client.register_merchant(&Address::generate(&env));
+    assert_eq!(client.get_merchant(&1).account, None);
+}
+
+#[test]
+fn test_deploy_account_for_existing_merchant() {
+    let (env, client, _contract_id, admin) = setup_test();
+    let merchant = Address::generate(&env);
+    client.register_merchant(&merchant);
+    client.verify_merchant(&admin, &1, &true);
+
+    upload_account_wasm(&env, &client, &admin);
+    let account = client.deploy_merchant_account(&merchant, &1);
+    assert_eq!(client.get_merchant(&1).account, Some(account.clone()));
+
+    let account_client = MerchantAccountClient::new(&env, &account);
+    assert_eq!(account_client.get_merchant(), merchant);
+    assert!(account_client.is_verified_account());
+}
+
+#[should_panic(expected = "HostError: Error(Contract, #56)")]
+#[test]
+fn test_deploy_account_twice() {
+    let (env, client, _contract_id, admin) = setup_test();
+    upload_account_wasm(&env, &client, &admin);
+    client.register_merchant(&Address::generate(&env));
+
+    client.deploy_merchant_account(&admin, &1);
+}
+
+#[should_panic(expected = "HostError: Error(Contract, #57)")]
+#[test]
+fn test_deploy_account_without_wasm_hash() {
+    let (env, client, _contract_id, admin) = setup_test();
+    client.register_merchant(&Address::generate(&env));
+
+    client.deploy_merchant_account(&admin, &1);
+}
+
+#[should_panic(expected = "HostError: Error(Contract, #1)")]
+#[test]
+fn test_deploy_account_by_unrelated_address() {
+    let (env, client, _contract_id, admin) = setup_test();
+    client.register_merchant(&Address::generate(&env));
+    upload_account_wasm(&env, &client, &admin);
+
+    client.deploy_merchant_account(&Address::generate(&env), &1);
+}
+
+#[should_panic(expected = "HostError: Error(Contract, #1)")]
+#[test]
+fn test_deployed_account_cannot_be_reinitialized() {
+    let (env, client, _contract_id, admin) = setup_test();
+    upload_account_wasm(&env, &client, &admin);
+
+    let merchant = Address::generate(&env);
+    client.register_merchant(&merchant);
+
+    let account = client.get_merchant(&1).account.unwrap();
+    MerchantAccountClient::new(&env, &account).initialize(
+        &Address::generate(&env),
+        &Address::generate(&env),
+        &1,
+    );
+}
+
+#[should_panic(expected = "HostError: Error(Contract, #1)")]
+#[test]
+fn test_set_account_wasm_hash_by_non_admin() {
+    let (env, client, _contract_id, _admin) = setup_test();
+    let wasm_hash = env.deployer().upload_contract_wasm(MERCHANT_ACCOUNT_WASM);
+    client.set_account_wasm_hash(&Address::generate(&env), &wasm_hash);
+}
@@ -0,0 +1,188 @@
+#![cfg(test)]
+
+use crate::shade::{Shade, ShadeClient};
+use crate::types::{InvoiceSpec, InvoiceStatus};
+use soroban_sdk::testutils::{Address as _, Events as _, Ledger};
+use soroban_sdk::{token, Address, Env, String, Symbol, TryIntoVal, Vec};
+
+fn setup_test() -> (Env, ShadeClient<'static>, Address, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(Shade, ());
+    let client = ShadeClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+    (env, client, contract_id, admin)
+}
+
+fn setup_invoice(env: &Env, client: &ShadeClient) -> (Address, Address, Address, u64) {
+    let merchant = Address::generate(env);
+    client.register_merchant(&merchant);
+
+    let token_admin = Address::generate(env);
+    let token = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    client.add_accepted_token(&client.get_admin(), &token);
+
+    let payer = Address::generate(env);
+    token::StellarAssetClient::new(env, &token).mint(&payer, &5000);
+
+    let invoice_id = client.create_invoice(
+        &merchant,
+        &InvoiceSpec {
+            description: String::from_str(env, "B2B Invoice"),
+            amount: 1000,
+            token: token.clone(),
+            expires_at: None,
+            escrow: false,
+            line_items: Vec::new(env),
+            allowed_payers: Vec::new(env),
+            reference: None,
+            splits: Vec::new(env),
+        },
+    );
+
+    (merchant, payer, token, invoice_id)
+}
+
+fn latest_event_name(env: &Env) -> Symbol {
+    let events = env.events().all();
+    let (_contract_id, topics, _data) = events.get(events.len() - 1).unwrap();
+    topics.get(0).unwrap().try_into_val(env).unwrap()
+}
+
+#[test]
+fn test_installments_settle_invoice() {
+    let (env, client, _contract_id, _admin) = setup_test();
+    let (merchant, payer, token, invoice_id) = setup_invoice(&env, &client);
+
+    env.ledger().set_timestamp(100);
+    client.pay_invoice_partial(&payer, &invoice_id, &300);
+    assert_eq!(
+        latest_event_name(&env),
+        Symbol::new(&env, "invoice_partially_paid_event")
+    );
+
+    let invoice = client.get_invoice(&invoice_id);
+    assert_eq!(invoice.status, InvoiceStatus::PartiallyPaid);
+    assert_eq!(invoice.amount_paid, 300);
+    assert_eq!(invoice.amount_due, 700);
+    assert_eq!(invoice.date_paid, None);
+
+    env.ledger().set_timestamp(200);
+    client.pay_invoice_partial(&payer, &invoice_id, &700);
+    assert_eq!(
+        latest_event_name(&env),
+        Symbol::new(&env, "invoice_paid_event")
+    );
+
+    let invoice = client.get_invoice(&invoice_id);
+    assert_eq!(invoice.status, InvoiceStatus::Paid);
+    assert_eq!(invoice.amount_paid, 1000);
+    assert_eq!(invoice.amount_due, 0);
+    assert_eq!(invoice.date_paid, Some(200));
+
+    let payments = client.get_invoice_payments(&invoice_id);
+    assert_eq!(payments.len(), 2);
+    assert_eq!(payments.get(0).unwrap().amount, 300);
+    assert_eq!(payments.get(0).unwrap().timestamp, 100);
+    assert_eq!(payments.get(1).unwrap().amount, 700);
+    assert_eq!(payments.get(1).unwrap().payer, payer);
+
+    assert_eq!(token::Client::new(&env, &token).balance(&merchant), 1000);
+}
+
+#[test]
+fn test_pay_invoice_settles_outstanding_balance() {
+    let (env, client, _contract_id, _admin) = setup_test();
+    let (merchant, payer, token, invoice_id) = setup_invoice(&env, &client);
+
+    client.pay_invoice_partial(&payer, &invoice_id, &400);
+
+    let other_payer = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&other_payer, &600);
+    client.pay_invoice(&other_payer, &invoice_id);
+
+    let invoice = client.get_invoice(&invoice_id);
+    assert_eq!(invoice.status, InvoiceStatus::Paid);
+    assert_eq!(invoice.payer, Some(payer));
+
+    let payments = client.get_invoice_payments(&invoice_id);
+    assert_eq!(payments.len(), 2);
+    assert_eq!(payments.get(1).unwrap().payer, other_payer);
+    assert_eq!(payments.get(1).unwrap().amount, 600);
+
+    let token_client = token::Client::new(&env, &token);
+    assert_eq!(token_client.balance(&other_payer), 0);
+    assert_eq!(token_client.balance(&merchant), 1000);
+}
+
+#[should_panic(expected = "HostError: Error(Contract, #22)")]
+#[test]
+fn test_overpayment_rejected() {
+    let (env, client, _contract_id, _admin) = setup_test();
+    let (_merchant, payer, _token, invoice_id) = setup_invoice(&env, &client);
+
+    client.pay_invoice_partial(&payer, &invoice_id, &600);
+    client.pay_invoice_partial(&payer, &invoice_id, &600);
+}
+
+#[should_panic(expected = "HostError: Error(Contract, #7)")]
+#[test]
+fn test_partial_payment_invalid_amount() {
+    let (env, client, _contract_id, _admin) = setup_test();
+    let (_merchant, payer, _token, invoice_id) = setup_invoice(&env, &client);
+
+    client.pay_invoice_partial(&payer, &invoice_id, &0);
+}
+
+#[test]
+fn test_refund_partially_paid_invoice() {
+    let (env, client, _contract_id, _admin) = setup_test();
+    let (merchant, payer, _token, invoice_id) = setup_invoice(&env, &client);
+
+    client.pay_invoice_partial(&payer, &invoice_id, &250);
+
+    let result = client.try_refund_invoice(&merchant, &invoice_id, &300);
+    assert!(result.is_err());
+
+    client.refund_invoice(&merchant, &invoice_id, &250);
+    let invoice = client.get_invoice(&invoice_id);
+    assert_eq!(invoice.status, InvoiceStatus::Refunded);
+    assert_eq!(invoice.amount_refunded, 250);
+}
+
+#[test]
+fn test_flat_fee_charged_once_across_installments() {
+    let (env, client, _contract_id, admin) = setup_test();
+    let (merchant, payer, token, invoice_id) = setup_invoice(&env, &client);
+    client.set_token_fee(&admin, &token, &100, &10);
+
+    client.pay_invoice_partial(&payer, &invoice_id, &300);
+    client.pay_invoice_partial(&payer, &invoice_id, &300);
+    assert_eq!(client.get_collected_fees(&token), 6);
+
+    client.pay_invoice_partial(&payer, &invoice_id, &400);
+    assert_eq!(client.get_collected_fees(&token), 20);
+    assert_eq!(token::Client::new(&env, &token).balance(&merchant), 980);
+}
+
+#[test]
+fn test_refund_split_pro_rata_across_payers() {
+    let (env, client, _contract_id, _admin) = setup_test();
+    let (merchant, payer, token, invoice_id) = setup_invoice(&env, &client);
+
+    let other_payer = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&other_payer, &600);
+    client.pay_invoice_partial(&payer, &invoice_id, &400);
+    client.pay_invoice(&other_payer, &invoice_id);
+
+    client.refund_invoice(&merchant, &invoice_id, &500);
+
+    let token_client = token::Client::new(&env, &token);
+    assert_eq!(token_client.balance(&payer), 4800);
+    assert_eq!(token_client.balance(&other_payer), 300);
+    assert_eq!(token_client.balance(&merchant), 500);
+    assert_eq!(client.get_invoice(&invoice_id).amount_refunded, 500);
+}
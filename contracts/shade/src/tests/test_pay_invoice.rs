@@ -0,0 +1,197 @@
+#![cfg(test)]
+
+use crate::shade::{Shade, ShadeClient};
+use crate::types::{InvoiceSpec, InvoiceStatus};
+use soroban_sdk::testutils::{Address as _, Events as _, Ledger};
+use soroban_sdk::{token, Address, Env, Map, String, Symbol, TryIntoVal, Val, Vec};
+
+fn setup_test() -> (Env, ShadeClient<'static>, Address, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(Shade, ());
+    let client = ShadeClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+    (env, client, contract_id, admin)
+}
+
+fn create_test_token(env: &Env, client: &ShadeClient) -> Address {
+    let token_admin = Address::generate(env);
+    let token = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    client.add_accepted_token(&client.get_admin(), &token);
+    token
+}
+
+#[test]
+fn test_pay_invoice_success() {
+    let (env, client, contract_id, _admin) = setup_test();
+
+    let merchant = Address::generate(&env);
+    client.register_merchant(&merchant);
+
+    let token = create_test_token(&env, &client);
+    let payer = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&payer, &5000);
+
+    let amount: i128 = 1000;
+    let invoice_id = client.create_invoice(
+        &merchant,
+        &InvoiceSpec {
+            description: String::from_str(&env, "Test Invoice"),
+            amount,
+            token: token.clone(),
+            expires_at: None,
+            escrow: false,
+            line_items: Vec::new(&env),
+            allowed_payers: Vec::new(&env),
+            reference: None,
+            splits: Vec::new(&env),
+        },
+    );
+
+    env.ledger().set_timestamp(12345);
+    client.pay_invoice(&payer, &invoice_id);
+
+    let events = env.events().all();
+    let (event_contract_id, topics, data) = events.get(events.len() - 1).unwrap();
+    assert_eq!(event_contract_id, contract_id);
+
+    let event_name: Symbol = topics.get(0).unwrap().try_into_val(&env).unwrap();
+    assert_eq!(event_name, Symbol::new(&env, "invoice_paid_event"));
+
+    let data_map: Map<Symbol, Val> = data.try_into_val(&env).unwrap();
+    let payer_in_event: Address = data_map
+        .get(Symbol::new(&env, "payer"))
+        .unwrap()
+        .try_into_val(&env)
+        .unwrap();
+    let amount_in_event: i128 = data_map
+        .get(Symbol::new(&env, "amount"))
+        .unwrap()
+        .try_into_val(&env)
+        .unwrap();
+    let timestamp_in_event: u64 = data_map
+        .get(Symbol::new(&env, "timestamp"))
+        .unwrap()
+        .try_into_val(&env)
+        .unwrap();
+    assert_eq!(payer_in_event, payer);
+    assert_eq!(amount_in_event, amount);
+    assert_eq!(timestamp_in_event, 12345);
+
+    let invoice = client.get_invoice(&invoice_id);
+    assert_eq!(invoice.status, InvoiceStatus::Paid);
+    assert_eq!(invoice.payer, Some(payer.clone()));
+    assert_eq!(invoice.date_paid, Some(12345));
+
+    let token_client = token::Client::new(&env, &token);
+    assert_eq!(token_client.balance(&payer), 4000);
+    assert_eq!(token_client.balance(&merchant), amount);
+}
+
+#[should_panic(expected = "HostError: Error(Contract, #12)")]
+#[test]
+fn test_pay_invoice_twice() {
+    let (env, client, _contract_id, _admin) = setup_test();
+
+    let merchant = Address::generate(&env);
+    client.register_merchant(&merchant);
+
+    let token = create_test_token(&env, &client);
+    let payer = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&payer, &5000);
+
+    let invoice_id = client.create_invoice(
+        &merchant,
+        &InvoiceSpec {
+            description: String::from_str(&env, "Test Invoice"),
+            amount: 1000,
+            token: token.clone(),
+            expires_at: None,
+            escrow: false,
+            line_items: Vec::new(&env),
+            allowed_payers: Vec::new(&env),
+            reference: None,
+            splits: Vec::new(&env),
+        },
+    );
+
+    client.pay_invoice(&payer, &invoice_id);
+    client.pay_invoice(&payer, &invoice_id);
+}
+
+#[should_panic(expected = "HostError: Error(Contract, #8)")]
+#[test]
+fn test_pay_invoice_not_found() {
+    let (env, client, _contract_id, _admin) = setup_test();
+
+    let payer = Address::generate(&env);
+    client.pay_invoice(&payer, &999);
+}
+
+#[should_panic(expected = "HostError: Error(Contract, #9)")]
+#[test]
+fn test_pay_invoice_when_paused() {
+    let (env, client, _contract_id, admin) = setup_test();
+
+    let merchant = Address::generate(&env);
+    client.register_merchant(&merchant);
+
+    let token = create_test_token(&env, &client);
+    let payer = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&payer, &5000);
+
+    let invoice_id = client.create_invoice(
+        &merchant,
+        &InvoiceSpec {
+            description: String::from_str(&env, "Test Invoice"),
+            amount: 1000,
+            token: token.clone(),
+            expires_at: None,
+            escrow: false,
+            line_items: Vec::new(&env),
+            allowed_payers: Vec::new(&env),
+            reference: None,
+            splits: Vec::new(&env),
+        },
+    );
+
+    client.pause(&admin);
+    client.pay_invoice(&payer, &invoice_id);
+}
+
+#[test]
+fn test_pay_invoice_insufficient_balance_leaves_invoice_pending() {
+    let (env, client, _contract_id, _admin) = setup_test();
+
+    let merchant = Address::generate(&env);
+    client.register_merchant(&merchant);
+
+    let token = create_test_token(&env, &client);
+    let payer = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&payer, &500);
+
+    let invoice_id = client.create_invoice(
+        &merchant,
+        &InvoiceSpec {
+            description: String::from_str(&env, "Test Invoice"),
+            amount: 1000,
+            token: token.clone(),
+            expires_at: None,
+            escrow: false,
+            line_items: Vec::new(&env),
+            allowed_payers: Vec::new(&env),
+            reference: None,
+            splits: Vec::new(&env),
+        },
+    );
+
+    let result = client.try_pay_invoice(&payer, &invoice_id);
+    assert!(result.is_err());
+
+    let invoice = client.get_invoice(&invoice_id);
+    assert_eq!(invoice.status, InvoiceStatus::Pending);
+    assert_eq!(invoice.payer, None);
+}
@@ -0,0 +1,176 @@
+#![cfg(test)]
+
+use crate::shade::{Shade, ShadeClient};
+use crate::types::{InvoiceSpec, InvoiceStatus, Role};
+use soroban_sdk::testutils::{Address as _, Events as _};
+use soroban_sdk::{token, Address, Env, Map, String, Symbol, TryIntoVal, Val, Vec};
+
+fn setup_test() -> (Env, ShadeClient<'static>, Address, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(Shade, ());
+    let client = ShadeClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+    (env, client, contract_id, admin)
+}
+
+fn setup_paid_invoice(env: &Env, client: &ShadeClient) -> (Address, Address, Address, u64) {
+    let merchant = Address::generate(env);
+    client.register_merchant(&merchant);
+
+    let token_admin = Address::generate(env);
+    let token = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    client.add_accepted_token(&client.get_admin(), &token);
+
+    let payer = Address::generate(env);
+    token::StellarAssetClient::new(env, &token).mint(&payer, &1000);
+
+    let invoice_id = client.create_invoice(
+        &merchant,
+        &InvoiceSpec {
+            description: String::from_str(env, "Test Invoice"),
+            amount: 1000,
+            token: token.clone(),
+            expires_at: None,
+            escrow: false,
+            line_items: Vec::new(env),
+            allowed_payers: Vec::new(env),
+            reference: None,
+            splits: Vec::new(env),
+        },
+    );
+    client.pay_invoice(&payer, &invoice_id);
+
+    (merchant, payer, token, invoice_id)
+}
+
+#[test]
+fn test_full_refund() {
+    let (env, client, contract_id, _admin) = setup_test();
+    let (merchant, payer, token, invoice_id) = setup_paid_invoice(&env, &client);
+
+    client.refund_invoice(&merchant, &invoice_id, &1000);
+
+    let events = env.events().all();
+    let (event_contract_id, topics, data) = events.get(events.len() - 1).unwrap();
+    assert_eq!(event_contract_id, contract_id);
+
+    let event_name: Symbol = topics.get(0).unwrap().try_into_val(&env).unwrap();
+    assert_eq!(event_name, Symbol::new(&env, "invoice_refunded_event"));
+
+    let data_map: Map<Symbol, Val> = data.try_into_val(&env).unwrap();
+    let amount_in_event: i128 = data_map
+        .get(Symbol::new(&env, "amount"))
+        .unwrap()
+        .try_into_val(&env)
+        .unwrap();
+    assert_eq!(amount_in_event, 1000);
+
+    let invoice = client.get_invoice(&invoice_id);
+    assert_eq!(invoice.status, InvoiceStatus::Refunded);
+    assert_eq!(invoice.amount_refunded, 1000);
+
+    let token_client = token::Client::new(&env, &token);
+    assert_eq!(token_client.balance(&payer), 1000);
+    assert_eq!(token_client.balance(&merchant), 0);
+}
+
+#[test]
+fn test_partial_refunds_accumulate() {
+    let (env, client, _contract_id, _admin) = setup_test();
+    let (merchant, payer, token, invoice_id) = setup_paid_invoice(&env, &client);
+
+    client.refund_invoice(&merchant, &invoice_id, &300);
+
+    let invoice = client.get_invoice(&invoice_id);
+    assert_eq!(invoice.status, InvoiceStatus::Paid);
+    assert_eq!(invoice.amount_refunded, 300);
+
+    client.refund_invoice(&merchant, &invoice_id, &700);
+
+    let invoice = client.get_invoice(&invoice_id);
+    assert_eq!(invoice.status, InvoiceStatus::Refunded);
+    assert_eq!(invoice.amount_refunded, 1000);
+    assert_eq!(token::Client::new(&env, &token).balance(&payer), 1000);
+}
+
+#[test]
+fn test_manager_can_refund_from_merchant_allowance() {
+    let (env, client, contract_id, admin) = setup_test();
+    let (merchant, payer, token, invoice_id) = setup_paid_invoice(&env, &client);
+
+    let manager = Address::generate(&env);
+    client.grant_role(&admin, &manager, &Role::Manager);
+
+    let token_client = token::Client::new(&env, &token);
+    token_client.approve(&merchant, &contract_id, &400, &1000);
+
+    client.refund_invoice(&manager, &invoice_id, &400);
+
+    assert_eq!(client.get_invoice(&invoice_id).amount_refunded, 400);
+    assert_eq!(token_client.balance(&payer), 400);
+    assert_eq!(token_client.allowance(&merchant, &contract_id), 0);
+}
+
+#[should_panic(expected = "HostError: Error(Contract, #1)")]
+#[test]
+fn test_refund_by_unauthorized_caller() {
+    let (env, client, _contract_id, _admin) = setup_test();
+    let (_merchant, _payer, _token, invoice_id) = setup_paid_invoice(&env, &client);
+
+    client.refund_invoice(&Address::generate(&env), &invoice_id, &100);
+}
+
+#[should_panic(expected = "HostError: Error(Contract, #18)")]
+#[test]
+fn test_refund_exceeding_paid_amount() {
+    let (env, client, _contract_id, _admin) = setup_test();
+    let (merchant, _payer, _token, invoice_id) = setup_paid_invoice(&env, &client);
+
+    client.refund_invoice(&merchant, &invoice_id, &600);
+    client.refund_invoice(&merchant, &invoice_id, &600);
+}
+
+#[should_panic(expected = "HostError: Error(Contract, #19)")]
+#[test]
+fn test_refund_with_insufficient_merchant_funds() {
+    let (env, client, _contract_id, _admin) = setup_test();
+    let (merchant, _payer, token, invoice_id) = setup_paid_invoice(&env, &client);
+
+    token::Client::new(&env, &token).transfer(&merchant, Address::generate(&env), &500);
+
+    client.refund_invoice(&merchant, &invoice_id, &1000);
+}
+
+#[should_panic(expected = "HostError: Error(Contract, #17)")]
+#[test]
+fn test_refund_pending_invoice() {
+    let (env, client, _contract_id, admin) = setup_test();
+
+    let merchant = Address::generate(&env);
+    client.register_merchant(&merchant);
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    client.add_accepted_token(&admin, &token);
+
+    let invoice_id = client.create_invoice(
+        &merchant,
+        &InvoiceSpec {
+            description: String::from_str(&env, "Test Invoice"),
+            amount: 1000,
+            token,
+            expires_at: None,
+            escrow: false,
+            line_items: Vec::new(&env),
+            allowed_payers: Vec::new(&env),
+            reference: None,
+            splits: Vec::new(&env),
+        },
+    );
+
+    client.refund_invoice(&merchant, &invoice_id, &100);
+}
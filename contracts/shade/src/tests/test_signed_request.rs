@@ -0,0 +1,149 @@
+#![cfg(test)]
+extern crate std;
+
+use crate::shade::{Shade, ShadeClient};
+use crate::types::{InvoiceStatus, PaymentRequest};
+use ed25519_dalek::{Signer, SigningKey};
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::xdr::ToXdr;
+use soroban_sdk::{token, Address, BytesN, Env, String};
+
+fn setup_test() -> (Env, ShadeClient<'static>, Address, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+    let contract_id = env.register(Shade, ());
+    let client = ShadeClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+    (env, client, contract_id, admin)
+}
+
+fn setup_merchant(env: &Env, client: &ShadeClient) -> (Address, Address, SigningKey) {
+    let merchant = Address::generate(env);
+    client.register_merchant(&merchant);
+
+    let signing_key = SigningKey::from_bytes(&[7u8; 32]);
+    client.set_merchant_key(
+        &merchant,
+        &BytesN::from_array(env, &signing_key.verifying_key().to_bytes()),
+    );
+
+    let token_admin = Address::generate(env);
+    let token = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    client.add_accepted_token(&client.get_admin(), &token);
+
+    (merchant, token, signing_key)
+}
+
+fn payment_request(env: &Env, merchant: &Address, token: &Address, nonce: u64) -> PaymentRequest {
+    PaymentRequest {
+        merchant: merchant.clone(),
+        description: String::from_str(env, "Checkout"),
+        amount: 750,
+        token: token.clone(),
+        nonce,
+        expires_at: 2_000,
+    }
+}
+
+fn sign(
+    env: &Env,
+    contract_id: &Address,
+    signing_key: &SigningKey,
+    request: &PaymentRequest,
+) -> BytesN<64> {
+    let mut payload = contract_id.clone().to_xdr(env);
+    payload.append(&request.clone().to_xdr(env));
+    let message: std::vec::Vec<u8> = payload.iter().collect();
+    BytesN::from_array(env, &signing_key.sign(&message).to_bytes())
+}
+
+#[test]
+fn test_pay_signed_request_creates_and_settles_invoice() {
+    let (env, client, contract_id, _admin) = setup_test();
+    let (merchant, token, signing_key) = setup_merchant(&env, &client);
+
+    let payer = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&payer, &1000);
+
+    let request = payment_request(&env, &merchant, &token, 1);
+    let signature = sign(&env, &contract_id, &signing_key, &request);
+    let invoice_id = client.pay_signed_request(&payer, &request, &signature);
+
+    let invoice = client.get_invoice(&invoice_id);
+    assert_eq!(invoice.status, InvoiceStatus::Paid);
+    assert_eq!(invoice.amount, 750);
+    assert_eq!(invoice.payer, Some(payer.clone()));
+    assert_eq!(token::Client::new(&env, &token).balance(&merchant), 750);
+}
+
+#[should_panic(expected = "HostError: Error(Contract, #48)")]
+#[test]
+fn test_replayed_request_rejected() {
+    let (env, client, contract_id, _admin) = setup_test();
+    let (merchant, token, signing_key) = setup_merchant(&env, &client);
+
+    let payer = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&payer, &2000);
+
+    let request = payment_request(&env, &merchant, &token, 1);
+    let signature = sign(&env, &contract_id, &signing_key, &request);
+    client.pay_signed_request(&payer, &request, &signature);
+    client.pay_signed_request(&payer, &request, &signature);
+}
+
+#[should_panic(expected = "HostError: Error(Contract, #47)")]
+#[test]
+fn test_expired_request_rejected() {
+    let (env, client, contract_id, _admin) = setup_test();
+    let (merchant, token, signing_key) = setup_merchant(&env, &client);
+
+    let request = payment_request(&env, &merchant, &token, 1);
+    let signature = sign(&env, &contract_id, &signing_key, &request);
+
+    env.ledger().set_timestamp(2_000);
+    client.pay_signed_request(&Address::generate(&env), &request, &signature);
+}
+
+#[test]
+fn test_tampered_request_rejected() {
+    let (env, client, contract_id, _admin) = setup_test();
+    let (merchant, token, signing_key) = setup_merchant(&env, &client);
+
+    let payer = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&payer, &1000);
+
+    let request = payment_request(&env, &merchant, &token, 1);
+    let signature = sign(&env, &contract_id, &signing_key, &request);
+
+    let mut tampered = request.clone();
+    tampered.amount = 1;
+    assert!(client
+        .try_pay_signed_request(&payer, &tampered, &signature)
+        .is_err());
+
+    let other_key = SigningKey::from_bytes(&[9u8; 32]);
+    let forged = sign(&env, &contract_id, &other_key, &request);
+    assert!(client
+        .try_pay_signed_request(&payer, &request, &forged)
+        .is_err());
+
+    client.pay_signed_request(&payer, &request, &signature);
+}
+
+#[should_panic(expected = "HostError: Error(Contract, #11)")]
+#[test]
+fn test_merchant_without_key() {
+    let (env, client, contract_id, _admin) = setup_test();
+
+    let merchant = Address::generate(&env);
+    client.register_merchant(&merchant);
+    let signing_key = SigningKey::from_bytes(&[7u8; 32]);
+
+    let request = payment_request(&env, &merchant, &Address::generate(&env), 1);
+    let signature = sign(&env, &contract_id, &signing_key, &request);
+    client.pay_signed_request(&Address::generate(&env), &request, &signature);
+}
@@ -0,0 +1,267 @@
+#![cfg(test)]
+
+use crate::shade::{Shade, ShadeClient};
+use crate::types::{InvoiceSpec, SplitPayout, SplitShare};
+use soroban_sdk::testutils::{Address as _, Events as _};
+use soroban_sdk::{token, vec, Address, Env, Map, String, Symbol, TryIntoVal, Val, Vec};
+
+fn setup_test() -> (Env, ShadeClient<'static>, Address, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(Shade, ());
+    let client = ShadeClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+    (env, client, contract_id, admin)
+}
+
+fn setup_merchant(env: &Env, client: &ShadeClient) -> (Address, Address) {
+    let merchant = Address::generate(env);
+    client.register_merchant(&merchant);
+
+    let token_admin = Address::generate(env);
+    let token = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    client.add_accepted_token(&client.get_admin(), &token);
+
+    (merchant, token)
+}
+
+fn spec(env: &Env, token: &Address, escrow: bool, splits: Vec<SplitShare>) -> InvoiceSpec {
+    InvoiceSpec {
+        description: String::from_str(env, "Marketplace order"),
+        amount: 1000,
+        token: token.clone(),
+        expires_at: None,
+        escrow,
+        line_items: Vec::new(env),
+        allowed_payers: Vec::new(env),
+        reference: None,
+        splits,
+    }
+}
+
+fn share(recipient: &Address, basis_points: u32) -> SplitShare {
+    SplitShare {
+        recipient: recipient.clone(),
+        basis_points,
+    }
+}
+
+fn find_event(env: &Env, name: &str) -> Map<Symbol, Val> {
+    for (_contract_id, topics, data) in env.events().all().iter() {
+        let event_name: Symbol = topics.get(0).unwrap().try_into_val(env).unwrap();
+        if event_name == Symbol::new(env, name) {
+            return data.try_into_val(env).unwrap();
+        }
+    }
+    panic!("event not found");
+}
+
+#[test]
+fn test_payment_distributed_with_dust_to_merchant() {
+    let (env, client, _contract_id, _admin) = setup_test();
+    let (merchant, token) = setup_merchant(&env, &client);
+    let (alice, bob, carol) = (
+        Address::generate(&env),
+        Address::generate(&env),
+        Address::generate(&env),
+    );
+
+    let splits = vec![
+        &env,
+        share(&alice, 3_333),
+        share(&bob, 3_333),
+        share(&carol, 3_334),
+    ];
+    let invoice_id = client.create_invoice(&merchant, &spec(&env, &token, false, splits));
+
+    let payer = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&payer, &1000);
+    client.pay_invoice(&payer, &invoice_id);
+
+    let data = find_event(&env, "invoice_split_settled_event");
+    let merchant_amount: i128 = data
+        .get(Symbol::new(&env, "merchant_amount"))
+        .unwrap()
+        .try_into_val(&env)
+        .unwrap();
+    assert_eq!(merchant_amount, 1);
+
+    let token_client = token::Client::new(&env, &token);
+    assert_eq!(token_client.balance(&alice), 333);
+    assert_eq!(token_client.balance(&bob), 333);
+    assert_eq!(token_client.balance(&carol), 333);
+    assert_eq!(token_client.balance(&merchant), 1);
+
+    let invoice = client.get_invoice(&invoice_id);
+    assert_eq!(invoice.splits.len(), 3);
+    assert_eq!(
+        invoice.split_payouts.get(2).unwrap(),
+        SplitPayout {
+            recipient: carol,
+            amount: 333,
+        }
+    );
+}
+
+#[test]
+fn test_partial_payments_accumulate_payouts() {
+    let (env, client, _contract_id, _admin) = setup_test();
+    let (merchant, token) = setup_merchant(&env, &client);
+    let partner = Address::generate(&env);
+
+    let splits = vec![&env, share(&merchant, 8_000), share(&partner, 2_000)];
+    let invoice_id = client.create_invoice(&merchant, &spec(&env, &token, false, splits));
+
+    let payer = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&payer, &1000);
+    client.pay_invoice_partial(&payer, &invoice_id, &400);
+    client.pay_invoice_partial(&payer, &invoice_id, &600);
+
+    let invoice = client.get_invoice(&invoice_id);
+    assert_eq!(invoice.split_payouts.get(0).unwrap().amount, 800);
+    assert_eq!(invoice.split_payouts.get(1).unwrap().amount, 200);
+    assert_eq!(token::Client::new(&env, &token).balance(&partner), 200);
+}
+
+#[test]
+fn test_merchant_default_splits_apply_to_new_invoices() {
+    let (env, client, _contract_id, _admin) = setup_test();
+    let (merchant, token) = setup_merchant(&env, &client);
+    let partner = Address::generate(&env);
+    let other = Address::generate(&env);
+
+    let defaults = vec![&env, share(&merchant, 9_000), share(&partner, 1_000)];
+    client.set_merchant_splits(&merchant, &defaults);
+    assert_eq!(client.get_merchant_splits(&merchant), defaults);
+
+    let default_id = client.create_invoice(&merchant, &spec(&env, &token, false, Vec::new(&env)));
+    assert_eq!(client.get_invoice(&default_id).splits, defaults);
+
+    let override_splits = vec![&env, share(&other, 10_000)];
+    let override_id = client.create_invoice(
+        &merchant,
+        &spec(&env, &token, false, override_splits.clone()),
+    );
+    assert_eq!(client.get_invoice(&override_id).splits, override_splits);
+
+    client.set_merchant_splits(&merchant, &Vec::new(&env));
+    let plain_id = client.create_invoice(&merchant, &spec(&env, &token, false, Vec::new(&env)));
+    assert!(client.get_invoice(&plain_id).splits.is_empty());
+}
+
+#[test]
+fn test_escrow_release_distributes_splits() {
+    let (env, client, _contract_id, admin) = setup_test();
+    let (merchant, token) = setup_merchant(&env, &client);
+    let partner = Address::generate(&env);
+    client.set_token_fee(&admin, &token, &100, &0);
+
+    let splits = vec![&env, share(&merchant, 7_500), share(&partner, 2_500)];
+    let invoice_id = client.create_invoice(&merchant, &spec(&env, &token, true, splits));
+
+    let payer = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&payer, &1000);
+    client.pay_invoice(&payer, &invoice_id);
+    assert!(client.get_invoice(&invoice_id).split_payouts.is_empty());
+
+    client.confirm_delivery(&payer, &invoice_id);
+
+    let token_client = token::Client::new(&env, &token);
+    assert_eq!(token_client.balance(&partner), 247);
+    assert_eq!(token_client.balance(&merchant), 743);
+}
+
+#[test]
+fn test_escrowed_split_invoice_refunded_from_custody() {
+    let (env, client, _contract_id, _admin) = setup_test();
+    let (merchant, token) = setup_merchant(&env, &client);
+    let partner = Address::generate(&env);
+
+    let splits = vec![&env, share(&merchant, 7_500), share(&partner, 2_500)];
+    let invoice_id = client.create_invoice(&merchant, &spec(&env, &token, true, splits));
+
+    let payer = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&payer, &1000);
+    client.pay_invoice(&payer, &invoice_id);
+    client.refund_invoice(&merchant, &invoice_id, &1000);
+
+    assert_eq!(token::Client::new(&env, &token).balance(&payer), 1000);
+}
+
+#[should_panic(expected = "HostError: Error(Contract, #55)")]
+#[test]
+fn test_settled_split_refund_capped_at_merchant_share() {
+    let (env, client, _contract_id, _admin) = setup_test();
+    let (merchant, token) = setup_merchant(&env, &client);
+    let partner = Address::generate(&env);
+
+    let splits = vec![&env, share(&merchant, 7_500), share(&partner, 2_500)];
+    let invoice_id = client.create_invoice(&merchant, &spec(&env, &token, false, splits));
+
+    let payer = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&payer, &1000);
+    client.pay_invoice(&payer, &invoice_id);
+
+    // The partner's 25% has already been paid out; the merchant can only
+    // refund the 75% it kept.
+    client.refund_invoice(&merchant, &invoice_id, &750);
+    assert_eq!(token::Client::new(&env, &token).balance(&payer), 750);
+    client.refund_invoice(&merchant, &invoice_id, &1);
+}
+
+#[test]
+fn test_default_split_invoice_refunded_from_merchant_share() {
+    let (env, client, _contract_id, _admin) = setup_test();
+    let (merchant, token) = setup_merchant(&env, &client);
+    let partner = Address::generate(&env);
+    client.set_merchant_splits(
+        &merchant,
+        &vec![&env, share(&merchant, 8_000), share(&partner, 2_000)],
+    );
+
+    let invoice_id = client.create_invoice(&merchant, &spec(&env, &token, false, Vec::new(&env)));
+    let payer = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&payer, &1000);
+    client.pay_invoice(&payer, &invoice_id);
+
+    client.refund_invoice(&merchant, &invoice_id, &500);
+    client.refund_invoice(&merchant, &invoice_id, &300);
+
+    let token_client = token::Client::new(&env, &token);
+    assert_eq!(token_client.balance(&payer), 800);
+    assert_eq!(token_client.balance(&partner), 200);
+    assert_eq!(client.get_invoice(&invoice_id).amount_refunded, 800);
+    assert!(client
+        .try_refund_invoice(&merchant, &invoice_id, &1)
+        .is_err());
+}
+
+#[should_panic(expected = "HostError: Error(Contract, #30)")]
+#[test]
+fn test_splits_must_sum_to_full_amount() {
+    let (env, client, _contract_id, _admin) = setup_test();
+    let (merchant, token) = setup_merchant(&env, &client);
+
+    let splits = vec![
+        &env,
+        share(&Address::generate(&env), 5_000),
+        share(&Address::generate(&env), 4_000),
+    ];
+    client.create_invoice(&merchant, &spec(&env, &token, false, splits));
+}
+
+#[should_panic(expected = "HostError: Error(Contract, #30)")]
+#[test]
+fn test_duplicate_split_recipient_rejected() {
+    let (env, client, _contract_id, _admin) = setup_test();
+    let (merchant, _token) = setup_merchant(&env, &client);
+    let partner = Address::generate(&env);
+
+    client.set_merchant_splits(
+        &merchant,
+        &vec![&env, share(&partner, 5_000), share(&partner, 5_000)],
+    );
+}
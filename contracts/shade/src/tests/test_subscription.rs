@@ -0,0 +1,223 @@
+#![cfg(test)]
+
+use crate::shade::{Shade, ShadeClient};
+use crate::types::{InvoiceStatus, SubscriptionStatus};
+use soroban_sdk::testutils::{Address as _, Events as _, Ledger};
+use soroban_sdk::{token, Address, Env, String, Symbol, TryIntoVal};
+
+const MONTH: u64 = 30 * 24 * 60 * 60;
+
+fn setup_test() -> (Env, ShadeClient<'static>, Address, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+    let contract_id = env.register(Shade, ());
+    let client = ShadeClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+    (env, client, contract_id, admin)
+}
+
+fn setup_subscription(
+    env: &Env,
+    client: &ShadeClient,
+    contract_id: &Address,
+    trial_period: u64,
+) -> (Address, Address, Address, u64) {
+    let merchant = Address::generate(env);
+    client.register_merchant(&merchant);
+
+    let token_admin = Address::generate(env);
+    let token = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    client.add_accepted_token(&client.get_admin(), &token);
+
+    let subscriber = Address::generate(env);
+    token::StellarAssetClient::new(env, &token).mint(&subscriber, &1000);
+    token::Client::new(env, &token).approve(&subscriber, contract_id, &1000, &100_000);
+
+    let plan_id = client.create_plan(
+        &merchant,
+        &String::from_str(env, "Pro plan"),
+        &token,
+        &250,
+        &MONTH,
+        &trial_period,
+    );
+    let subscription_id = client.subscribe(&subscriber, &plan_id);
+
+    (merchant, subscriber, token, subscription_id)
+}
+
+fn latest_event_name(env: &Env) -> Symbol {
+    let events = env.events().all();
+    let (_contract_id, topics, _data) = events.get(events.len() - 1).unwrap();
+    topics.get(0).unwrap().try_into_val(env).unwrap()
+}
+
+#[test]
+fn test_charge_subscription_generates_paid_invoice() {
+    let (env, client, contract_id, admin) = setup_test();
+    let (merchant, subscriber, token, subscription_id) =
+        setup_subscription(&env, &client, &contract_id, 0);
+    client.set_token_fee(&admin, &token, &400, &0);
+
+    let invoice_id = client.charge_subscription(&subscription_id).unwrap();
+    assert_eq!(
+        latest_event_name(&env),
+        Symbol::new(&env, "subscription_charged_event")
+    );
+
+    let invoice = client.get_invoice(&invoice_id);
+    assert_eq!(invoice.status, InvoiceStatus::Paid);
+    assert_eq!(invoice.amount, 250);
+    assert_eq!(invoice.payer, Some(subscriber.clone()));
+    assert_eq!(invoice.date_paid, Some(1_000));
+
+    let subscription = client.get_subscription(&subscription_id);
+    assert_eq!(subscription.next_charge_at, 1_000 + MONTH);
+    assert_eq!(subscription.last_charged_at, Some(1_000));
+
+    let token_client = token::Client::new(&env, &token);
+    assert_eq!(token_client.balance(&subscriber), 750);
+    assert_eq!(token_client.balance(&merchant), 240);
+    assert_eq!(client.get_collected_fees(&token), 10);
+}
+
+#[should_panic(expected = "HostError: Error(Contract, #34)")]
+#[test]
+fn test_charge_before_period_elapsed() {
+    let (env, client, contract_id, _admin) = setup_test();
+    let (_merchant, _subscriber, _token, subscription_id) =
+        setup_subscription(&env, &client, &contract_id, 0);
+
+    client.charge_subscription(&subscription_id);
+    env.ledger().set_timestamp(1_000 + MONTH - 1);
+    client.charge_subscription(&subscription_id);
+}
+
+#[test]
+fn test_trial_period_delays_first_charge() {
+    let (env, client, contract_id, _admin) = setup_test();
+    let (_merchant, _subscriber, _token, subscription_id) =
+        setup_subscription(&env, &client, &contract_id, 7 * 24 * 60 * 60);
+
+    assert!(client.try_charge_subscription(&subscription_id).is_err());
+
+    env.ledger().set_timestamp(1_000 + 7 * 24 * 60 * 60);
+    assert!(client.charge_subscription(&subscription_id).is_some());
+}
+
+#[test]
+fn test_charge_failure_emits_event() {
+    let (env, client, contract_id, _admin) = setup_test();
+    let (merchant, subscriber, token, subscription_id) =
+        setup_subscription(&env, &client, &contract_id, 0);
+
+    let token_client = token::Client::new(&env, &token);
+    token_client.transfer(&subscriber, &merchant, &900);
+
+    assert_eq!(client.charge_subscription(&subscription_id), None);
+    assert_eq!(
+        latest_event_name(&env),
+        Symbol::new(&env, "subscription_charge_failed_event")
+    );
+
+    let subscription = client.get_subscription(&subscription_id);
+    assert_eq!(subscription.next_charge_at, 1_000);
+    assert_eq!(subscription.last_charged_at, None);
+    assert_eq!(token_client.balance(&subscriber), 100);
+}
+
+#[test]
+fn test_pause_resume_and_cancel() {
+    let (env, client, contract_id, _admin) = setup_test();
+    let (merchant, subscriber, _token, subscription_id) =
+        setup_subscription(&env, &client, &contract_id, 0);
+
+    client.pause_subscription(&subscriber, &subscription_id);
+    assert_eq!(
+        client.get_subscription(&subscription_id).status,
+        SubscriptionStatus::Paused
+    );
+    assert!(client.try_charge_subscription(&subscription_id).is_err());
+
+    client.resume_subscription(&subscriber, &subscription_id);
+    assert_eq!(
+        client.get_subscription(&subscription_id).status,
+        SubscriptionStatus::Active
+    );
+
+    client.cancel_subscription(&merchant, &subscription_id);
+    assert_eq!(
+        latest_event_name(&env),
+        Symbol::new(&env, "subscription_cancelled_event")
+    );
+    assert_eq!(
+        client.get_subscription(&subscription_id).status,
+        SubscriptionStatus::Cancelled
+    );
+    assert!(client.try_charge_subscription(&subscription_id).is_err());
+}
+
+#[test]
+fn test_resume_does_not_back_charge_paused_periods() {
+    let (env, client, contract_id, _admin) = setup_test();
+    let (merchant, subscriber, token, subscription_id) =
+        setup_subscription(&env, &client, &contract_id, 0);
+
+    client.charge_subscription(&subscription_id);
+    client.pause_subscription(&subscriber, &subscription_id);
+
+    env.ledger().set_timestamp(1_000 + 3 * MONTH);
+    client.resume_subscription(&subscriber, &subscription_id);
+    assert_eq!(
+        client.get_subscription(&subscription_id).next_charge_at,
+        1_000 + 3 * MONTH
+    );
+
+    client.charge_subscription(&subscription_id);
+    assert!(client.try_charge_subscription(&subscription_id).is_err());
+    assert_eq!(token::Client::new(&env, &token).balance(&merchant), 500);
+}
+
+#[should_panic(expected = "HostError: Error(Contract, #1)")]
+#[test]
+fn test_merchant_cannot_resume_subscriber_pause() {
+    let (env, client, contract_id, _admin) = setup_test();
+    let (merchant, subscriber, _token, subscription_id) =
+        setup_subscription(&env, &client, &contract_id, 0);
+
+    client.pause_subscription(&subscriber, &subscription_id);
+    client.resume_subscription(&merchant, &subscription_id);
+}
+
+#[should_panic(expected = "HostError: Error(Contract, #1)")]
+#[test]
+fn test_cancel_by_unrelated_address() {
+    let (env, client, contract_id, _admin) = setup_test();
+    let (_merchant, _subscriber, _token, subscription_id) =
+        setup_subscription(&env, &client, &contract_id, 0);
+
+    client.cancel_subscription(&Address::generate(&env), &subscription_id);
+}
+
+#[should_panic(expected = "HostError: Error(Contract, #35)")]
+#[test]
+fn test_create_plan_zero_period() {
+    let (env, client, _contract_id, _admin) = setup_test();
+
+    let merchant = Address::generate(&env);
+    client.register_merchant(&merchant);
+    let token = Address::generate(&env);
+
+    client.create_plan(
+        &merchant,
+        &String::from_str(&env, "Broken plan"),
+        &token,
+        &250,
+        &0,
+        &0,
+    );
+}
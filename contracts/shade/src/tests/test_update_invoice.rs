@@ -0,0 +1,195 @@
+#![cfg(test)]
+
+use crate::shade::{Shade, ShadeClient};
+use crate::types::{ExpiryUpdate, InvoiceSpec};
+use soroban_sdk::testutils::{Address as _, Events as _, Ledger};
+use soroban_sdk::{token, Address, Env, Map, String, Symbol, TryIntoVal, Val, Vec};
+
+fn setup_test() -> (Env, ShadeClient<'static>, Address, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+    let contract_id = env.register(Shade, ());
+    let client = ShadeClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+    (env, client, contract_id, admin)
+}
+
+fn create_test_token(env: &Env, client: &ShadeClient) -> Address {
+    let token_admin = Address::generate(env);
+    let token = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    client.add_accepted_token(&client.get_admin(), &token);
+    token
+}
+
+fn setup_invoice(env: &Env, client: &ShadeClient) -> (Address, Address, u64) {
+    let merchant = Address::generate(env);
+    client.register_merchant(&merchant);
+    let token = create_test_token(env, client);
+
+    let invoice_id = client.create_invoice(
+        &merchant,
+        &InvoiceSpec {
+            description: String::from_str(env, "Design work"),
+            amount: 1000,
+            token: token.clone(),
+            expires_at: None,
+            escrow: false,
+            line_items: Vec::new(env),
+            allowed_payers: Vec::new(env),
+            reference: None,
+            splits: Vec::new(env),
+        },
+    );
+
+    (merchant, token, invoice_id)
+}
+
+#[test]
+fn test_update_invoice_bumps_version_and_keeps_history() {
+    let (env, client, _contract_id, _admin) = setup_test();
+    let (merchant, token, invoice_id) = setup_invoice(&env, &client);
+    assert_eq!(client.get_invoice(&invoice_id).version, 1);
+
+    let new_token = create_test_token(&env, &client);
+    client.update_invoice(
+        &merchant,
+        &invoice_id,
+        &Some(String::from_str(&env, "Design work (revised)")),
+        &Some(1200),
+        &Some(new_token.clone()),
+        &Some(ExpiryUpdate::Set(5_000)),
+    );
+
+    let events = env.events().all();
+    let (_event_contract_id, topics, data) = events.get(events.len() - 1).unwrap();
+    let event_name: Symbol = topics.get(0).unwrap().try_into_val(&env).unwrap();
+    assert_eq!(event_name, Symbol::new(&env, "invoice_updated_event"));
+    let data_map: Map<Symbol, Val> = data.try_into_val(&env).unwrap();
+    let version_in_event: u32 = data_map
+        .get(Symbol::new(&env, "version"))
+        .unwrap()
+        .try_into_val(&env)
+        .unwrap();
+    assert_eq!(version_in_event, 2);
+
+    let invoice = client.get_invoice(&invoice_id);
+    assert_eq!(invoice.version, 2);
+    assert_eq!(invoice.amount, 1200);
+    assert_eq!(invoice.token, new_token);
+    assert_eq!(invoice.expires_at, Some(5_000));
+    assert_eq!(
+        invoice.description,
+        String::from_str(&env, "Design work (revised)")
+    );
+
+    let original = client.get_invoice_version(&invoice_id, &1);
+    assert_eq!(original.version, 1);
+    assert_eq!(original.amount, 1000);
+    assert_eq!(original.token, token);
+    assert_eq!(client.get_invoice_version(&invoice_id, &2), invoice);
+}
+
+#[test]
+fn test_partial_update_keeps_other_fields() {
+    let (env, client, _contract_id, _admin) = setup_test();
+    let (merchant, token, invoice_id) = setup_invoice(&env, &client);
+
+    client.update_invoice(&merchant, &invoice_id, &None, &Some(900), &None, &None);
+    client.update_invoice(&merchant, &invoice_id, &None, &Some(950), &None, &None);
+
+    let invoice = client.get_invoice(&invoice_id);
+    assert_eq!(invoice.version, 3);
+    assert_eq!(invoice.amount, 950);
+    assert_eq!(invoice.token, token);
+    assert_eq!(client.get_invoice_version(&invoice_id, &2).amount, 900);
+}
+
+#[test]
+fn test_clear_expiry() {
+    let (env, client, _contract_id, _admin) = setup_test();
+    let (merchant, _token, invoice_id) = setup_invoice(&env, &client);
+
+    client.update_invoice(
+        &merchant,
+        &invoice_id,
+        &None,
+        &None,
+        &None,
+        &Some(ExpiryUpdate::Set(5_000)),
+    );
+    assert_eq!(client.get_invoice(&invoice_id).expires_at, Some(5_000));
+
+    client.update_invoice(
+        &merchant,
+        &invoice_id,
+        &None,
+        &None,
+        &None,
+        &Some(ExpiryUpdate::Clear),
+    );
+    let invoice = client.get_invoice(&invoice_id);
+    assert_eq!(invoice.expires_at, None);
+    assert_eq!(invoice.version, 3);
+}
+
+#[should_panic(expected = "HostError: Error(Contract, #21)")]
+#[test]
+fn test_update_expired_invoice() {
+    let (env, client, _contract_id, _admin) = setup_test();
+    let (merchant, _token, invoice_id) = setup_invoice(&env, &client);
+
+    client.update_invoice(
+        &merchant,
+        &invoice_id,
+        &None,
+        &None,
+        &None,
+        &Some(ExpiryUpdate::Set(2_000)),
+    );
+    env.ledger().set_timestamp(2_000);
+    client.update_invoice(
+        &merchant,
+        &invoice_id,
+        &None,
+        &None,
+        &None,
+        &Some(ExpiryUpdate::Set(9_000)),
+    );
+}
+
+#[should_panic(expected = "HostError: Error(Contract, #12)")]
+#[test]
+fn test_update_paid_invoice() {
+    let (env, client, _contract_id, _admin) = setup_test();
+    let (merchant, token, invoice_id) = setup_invoice(&env, &client);
+
+    let payer = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&payer, &1000);
+    client.pay_invoice(&payer, &invoice_id);
+
+    client.update_invoice(&merchant, &invoice_id, &None, &Some(10), &None, &None);
+}
+
+#[should_panic(expected = "HostError: Error(Contract, #1)")]
+#[test]
+fn test_update_invoice_by_other_merchant() {
+    let (env, client, _contract_id, _admin) = setup_test();
+    let (_merchant, _token, invoice_id) = setup_invoice(&env, &client);
+
+    let other_merchant = Address::generate(&env);
+    client.register_merchant(&other_merchant);
+    client.update_invoice(&other_merchant, &invoice_id, &None, &Some(10), &None, &None);
+}
+
+#[should_panic(expected = "HostError: Error(Contract, #8)")]
+#[test]
+fn test_get_unknown_invoice_version() {
+    let (env, client, _contract_id, _admin) = setup_test();
+    let (_merchant, _token, invoice_id) = setup_invoice(&env, &client);
+
+    client.get_invoice_version(&invoice_id, &2);
+}
@@ -1,8 +1,8 @@
 #![cfg(test)]
 use crate::shade::{Shade, ShadeClient};
-use crate::types::DataKey;
+use crate::types::{DataKey, InvoiceStatus, LegacyInvoice, LegacyMerchant};
 use soroban_sdk::testutils::{Address as _, Events as _};
-use soroban_sdk::{Address, BytesN, Env, Map, Symbol, TryIntoVal, Val, Vec};
+use soroban_sdk::{token, Address, BytesN, Env, Map, String, Symbol, TryIntoVal, Val, Vec};
 
 const V2_WASM: &[u8] = include_bytes!("fixtures/upgrade_v2_contract.wasm");
 
@@ -13,7 +13,7 @@ fn assert_latest_upgrade_event(
     expected_timestamp: u64,
 ) {
     let events = env.events().all();
-    assert!(events.len() > 0);
+    assert!(!events.is_empty());
 
     let (event_contract_id, topics, data) = events.get(events.len() - 1).unwrap();
     assert_eq!(event_contract_id, contract_id.clone());
@@ -106,3 +106,91 @@ fn test_upgrade_emits_contract_upgraded_event() {
     client.upgrade(&v2_hash);
     assert_latest_upgrade_event(&env, &contract_id, &v2_hash, expected_timestamp);
 }
+
+#[test]
+fn test_invoices_written_before_upgrade_still_load() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(Shade, ());
+    let client = ShadeClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    client.add_accepted_token(&admin, &token);
+
+    let merchant = Address::generate(&env);
+    client.register_merchant(&merchant);
+    env.as_contract(&contract_id, || {
+        let storage = env.storage().persistent();
+        storage.set(
+            &DataKey::Invoice(1),
+            &LegacyInvoice {
+                id: 1,
+                description: String::from_str(&env, "Legacy order"),
+                amount: 500,
+                token: token.clone(),
+                status: InvoiceStatus::Pending,
+                merchant_id: 1,
+                payer: None,
+                date_created: 0,
+                date_paid: None,
+            },
+        );
+        storage.set(&DataKey::InvoiceCount, &1u64);
+    });
+
+    let invoice = client.get_invoice(&1);
+    assert_eq!(invoice.amount_due, 500);
+    assert_eq!(invoice.version, 1);
+
+    let payer = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&payer, &500);
+    client.pay_invoice(&payer, &1);
+
+    let invoice = client.get_invoice(&1);
+    assert_eq!(invoice.status, InvoiceStatus::Paid);
+    assert_eq!(invoice.amount_paid, 500);
+    assert_eq!(token::Client::new(&env, &token).balance(&merchant), 500);
+}
+
+#[test]
+fn test_merchants_written_before_upgrade_still_load() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(Shade, ());
+    let client = ShadeClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let merchant = Address::generate(&env);
+    env.as_contract(&contract_id, || {
+        let storage = env.storage().persistent();
+        storage.set(
+            &DataKey::Merchant(1),
+            &LegacyMerchant {
+                id: 1,
+                address: merchant.clone(),
+                active: true,
+                verified: true,
+                date_registered: 0,
+            },
+        );
+        storage.set(&DataKey::MerchantId(merchant.clone()), &1u64);
+        storage.set(&DataKey::MerchantCount, &1u64);
+    });
+
+    let legacy_merchant = client.get_merchant(&1);
+    assert_eq!(legacy_merchant.address, merchant);
+    assert!(legacy_merchant.verified);
+    assert_eq!(legacy_merchant.account, None);
+
+    client.verify_merchant(&admin, &1, &false);
+    let updated = client.get_merchant(&1);
+    assert!(!updated.verified);
+    assert_eq!(updated.address, merchant);
+}
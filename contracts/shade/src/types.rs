@@ -1,4 +1,4 @@
-use soroban_sdk::{contracttype, Address};
+use soroban_sdk::{contracttype, Address, BytesN, Symbol, Vec};
 
 #[contracttype]
 pub enum DataKey {
@@ -18,6 +18,22 @@ pub enum DataKey {
     InvoiceCount,
     ReentrancyStatus,
     Role(Address, Role),
+    CollectedFees(Address),
+    InvoicePayments(u64),
+    EscrowReleaseWindow,
+    Dispute(u64),
+    DisputeWindow,
+    Plan(u64),
+    PlanCount,
+    Subscription(u64),
+    SubscriptionCount,
+    OracleConfig,
+    InvoiceReference(u64, soroban_sdk::String),
+    UsedNonce(Address, u64),
+    TokenLimits(Address),
+    InvoiceVersion(u64, u32),
+    MerchantSplits(u64),
+    AccountWasmHash,
 }
 
 #[contracttype]
@@ -35,6 +51,7 @@ pub struct Merchant {
     pub active: bool,
     pub verified: bool,
     pub date_registered: u64,
+    pub account: Option<Address>,
 }
 
 #[contracttype]
@@ -49,6 +66,81 @@ pub struct Invoice {
     pub payer: Option<Address>,
     pub date_created: u64,
     pub date_paid: Option<u64>,
+    pub expires_at: Option<u64>,
+    pub escrow: bool,
+    pub escrow_release_at: Option<u64>,
+    pub cancellation_reason: Option<u32>,
+    pub amount_paid: i128,
+    pub amount_due: i128,
+    pub amount_refunded: i128,
+    pub fiat_amount: Option<i128>,
+    pub currency: Option<Symbol>,
+    pub line_items: Vec<LineItem>,
+    pub subtotal: i128,
+    pub discount_amount: i128,
+    pub tax_amount: i128,
+    pub allowed_payers: Vec<Address>,
+    pub reference: Option<soroban_sdk::String>,
+    pub version: u32,
+    pub splits: Vec<SplitShare>,
+    pub split_payouts: Vec<SplitPayout>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InvoiceSpec {
+    pub description: soroban_sdk::String,
+    pub amount: i128,
+    pub token: Address,
+    pub expires_at: Option<u64>,
+    pub escrow: bool,
+    pub line_items: Vec<LineItem>,
+    pub allowed_payers: Vec<Address>,
+    pub reference: Option<soroban_sdk::String>,
+    pub splits: Vec<SplitShare>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PaymentRequest {
+    pub merchant: Address,
+    pub description: soroban_sdk::String,
+    pub amount: i128,
+    pub token: Address,
+    pub nonce: u64,
+    pub expires_at: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LineItem {
+    pub label: soroban_sdk::String,
+    pub quantity: u32,
+    pub unit_price: i128,
+    pub tax_basis_points: u32,
+    pub discount: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SplitShare {
+    pub recipient: Address,
+    pub basis_points: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SplitPayout {
+    pub recipient: Address,
+    pub amount: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InvoicePayment {
+    pub payer: Address,
+    pub amount: i128,
+    pub timestamp: u64,
 }
 
 #[contracttype]
@@ -59,6 +151,92 @@ pub enum InvoiceStatus {
     Paid = 1,
     Cancelled = 2,
     Refunded = 3,
+    Expired = 4,
+    PartiallyPaid = 5,
+    Escrowed = 6,
+    Released = 7,
+    Disputed = 8,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ExpiryUpdate {
+    Set(u64),
+    Clear,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Dispute {
+    pub invoice_id: u64,
+    pub payer: Address,
+    pub evidence_hash: BytesN<32>,
+    pub amount: i128,
+    pub opened_at: u64,
+    pub resolved: bool,
+    pub resolver: Option<Address>,
+    pub payer_amount: i128,
+    pub merchant_amount: i128,
+    pub resolved_at: Option<u64>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SubscriptionPlan {
+    pub id: u64,
+    pub merchant_id: u64,
+    pub description: soroban_sdk::String,
+    pub token: Address,
+    pub amount: i128,
+    pub period: u64,
+    pub trial_period: u64,
+    pub date_created: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Subscription {
+    pub id: u64,
+    pub plan_id: u64,
+    pub subscriber: Address,
+    pub status: SubscriptionStatus,
+    pub next_charge_at: u64,
+    pub last_charged_at: Option<u64>,
+    pub paused_by: Option<Address>,
+    pub date_created: u64,
+}
+
+#[contracttype]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum SubscriptionStatus {
+    Active = 0,
+    Paused = 1,
+    Cancelled = 2,
+}
+
+#[contracttype]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum CancellationReason {
+    Duplicate = 0,
+    IncorrectDetails = 1,
+    CustomerRequest = 2,
+    Other = 3,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenFee {
+    pub fee_in_basis_points: u32,
+    pub fee_amount: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenLimits {
+    pub min_amount: Option<i128>,
+    pub max_amount: Option<i128>,
 }
 
 #[contracttype]
@@ -75,6 +253,7 @@ pub struct InvoiceFilter {
     pub merchant: Option<Address>,
     pub min_amount: Option<u128>,
     pub max_amount: Option<u128>,
+    pub payer: Option<Address>,
 }
 
 #[contracttype]
@@ -84,3 +263,51 @@ pub enum Role {
     Manager,
     Operator,
 }
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OracleConfig {
+    pub oracle: Address,
+    pub max_age: u64,
+    pub max_deviation_basis_points: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Asset {
+    Stellar(Address),
+    Other(Symbol),
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PriceData {
+    pub price: i128,
+    pub timestamp: u64,
+}
+
+// Invoice records as stored before the invoice fields were extended.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LegacyInvoice {
+    pub id: u64,
+    pub description: soroban_sdk::String,
+    pub amount: i128,
+    pub token: Address,
+    pub status: InvoiceStatus,
+    pub merchant_id: u64,
+    pub payer: Option<Address>,
+    pub date_created: u64,
+    pub date_paid: Option<u64>,
+}
+
+// Merchant records as stored before merchant accounts were added.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LegacyMerchant {
+    pub id: u64,
+    pub address: Address,
+    pub active: bool,
+    pub verified: bool,
+    pub date_registered: u64,
+}